@@ -4,22 +4,22 @@ fn main() {
     let mut sqlite_formatter = sqlf::fmt::sqlite::SqliteFormatter::new();
 
     let root_query = Select::new("tests", (), &["first"]);
-    dbg!(sqlite_formatter.format(&root_query));
+    dbg!(sqlite_formatter.format(&root_query).unwrap());
 
     let nested_query = Select::new(
         Select::new("root", (), &["testing"]),
         (),
         vec!["test".to_string()],
     );
-    dbg!(sqlite_formatter.format(&nested_query));
+    dbg!(sqlite_formatter.format(&nested_query).unwrap());
 
     let with_condition = Select::new("testing", ("name", "value"), &["first"]);
-    dbg!(sqlite_formatter.format(&with_condition));
+    dbg!(sqlite_formatter.format(&with_condition).unwrap());
 
     let with_and_condition =
         Select::new("testing", ("first", "1").and(("second", "2")), &["first"]);
-    dbg!(sqlite_formatter.format(&with_and_condition));
+    dbg!(sqlite_formatter.format(&with_and_condition).unwrap());
 
     let with_or_condition = Select::new("testing", ("first", "1").or(("second", "2")), &["first"]);
-    dbg!(sqlite_formatter.format(&with_or_condition));
+    dbg!(sqlite_formatter.format(&with_or_condition).unwrap());
 }
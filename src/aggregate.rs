@@ -0,0 +1,217 @@
+use crate::{
+    fmt::{self, ExpressionBuilder, FieldsBuilder, FmtBuilder, FormatError},
+    sql::Sql,
+    Comparison, Expression, Fields,
+};
+
+/// The Aggregate-Functions that can be used to combine multiple Rows
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AggregateFunction {
+    /// Counts the Rows
+    Count,
+    /// Sums up the Values
+    Sum,
+    /// Calculates the Average of the Values
+    Avg,
+    /// Finds the smallest Value
+    Min,
+    /// Finds the largest Value
+    Max,
+    /// Concatenates the Values using the given Separator, known as GROUP_CONCAT or string_agg
+    /// depending on the Dialect
+    GroupConcat(String),
+}
+
+/// A Call to an Aggregate-Function, which can be selected like any other Field or be used as an
+/// [`Expression`], like in the HAVING Clause
+#[derive(Debug, Clone, PartialEq)]
+pub struct Aggregate {
+    function: AggregateFunction,
+    /// The Value the Function is applied to, None represents all Rows (`*`)
+    argument: Option<Expression>,
+    distinct: bool,
+    alias: Option<String>,
+}
+
+impl Aggregate {
+    /// Creates a new Call to the given Aggregate-Function
+    pub fn new<N>(function: AggregateFunction, argument: Option<N>) -> Self
+    where
+        N: Into<Expression>,
+    {
+        Self {
+            function,
+            argument: argument.map(|a| a.into()),
+            distinct: false,
+            alias: None,
+        }
+    }
+
+    /// Counts all the Rows, `COUNT(*)`
+    pub fn count_all() -> Self {
+        Self::new::<Expression>(AggregateFunction::Count, None)
+    }
+
+    /// Counts the Rows where the Column is not NULL
+    pub fn count<N>(column: N) -> Self
+    where
+        N: Into<Expression>,
+    {
+        Self::new(AggregateFunction::Count, Some(column))
+    }
+
+    /// Counts the distinct Values of the Column, `COUNT(DISTINCT column)`
+    pub fn count_distinct<N>(column: N) -> Self
+    where
+        N: Into<Expression>,
+    {
+        Self::count(column).distinct()
+    }
+
+    /// Sums up the Values of the Column
+    pub fn sum<N>(column: N) -> Self
+    where
+        N: Into<Expression>,
+    {
+        Self::new(AggregateFunction::Sum, Some(column))
+    }
+
+    /// Calculates the Average of the Column
+    pub fn avg<N>(column: N) -> Self
+    where
+        N: Into<Expression>,
+    {
+        Self::new(AggregateFunction::Avg, Some(column))
+    }
+
+    /// Finds the smallest Value of the Column
+    pub fn min<N>(column: N) -> Self
+    where
+        N: Into<Expression>,
+    {
+        Self::new(AggregateFunction::Min, Some(column))
+    }
+
+    /// Finds the largest Value of the Column
+    pub fn max<N>(column: N) -> Self
+    where
+        N: Into<Expression>,
+    {
+        Self::new(AggregateFunction::Max, Some(column))
+    }
+
+    /// Concatenates the Values of the Column using the given Separator
+    pub fn group_concat<N, S>(column: N, separator: S) -> Self
+    where
+        N: Into<Expression>,
+        S: Into<String>,
+    {
+        Self::new(
+            AggregateFunction::GroupConcat(separator.into()),
+            Some(column),
+        )
+    }
+
+    /// Only considers distinct Values
+    pub fn distinct(mut self) -> Self {
+        self.distinct = true;
+        self
+    }
+
+    /// Compares the Result of the Function with the other Value, like in a HAVING Clause
+    pub fn compare<R>(self, comparison: Comparison, right: R) -> Expression
+    where
+        R: Into<Expression>,
+    {
+        Expression::from(self).compare(comparison, right)
+    }
+
+    /// Sets the Name of the resulting Field
+    pub fn alias<N>(mut self, alias: N) -> Self
+    where
+        N: Into<String>,
+    {
+        self.alias = Some(alias.into());
+        self
+    }
+
+    /// The Name of the resulting Field, which is either the configured Alias or the Name of the
+    /// Function itself
+    pub fn name(&self) -> String {
+        if let Some(alias) = self.alias.as_ref() {
+            return alias.clone();
+        }
+
+        let func_name = match &self.function {
+            AggregateFunction::Count => "count",
+            AggregateFunction::Sum => "sum",
+            AggregateFunction::Avg => "avg",
+            AggregateFunction::Min => "min",
+            AggregateFunction::Max => "max",
+            AggregateFunction::GroupConcat(_) => "group_concat",
+        };
+        let arg = self
+            .argument
+            .as_ref()
+            .map_or_else(|| "*".to_string(), |a| a.name());
+        format!("{}({})", func_name, arg)
+    }
+
+    /// The Value the Function is applied to, None if it is applied to all Rows
+    pub fn argument(&self) -> Option<&Expression> {
+        self.argument.as_ref()
+    }
+
+    pub(crate) fn rename_column(&mut self, from: &str, to: &str) {
+        if let Some(argument) = self.argument.as_mut() {
+            argument.rename_column(from, to);
+        }
+    }
+
+    /// Formats only the Call itself, without the Alias
+    pub(crate) fn format_call<F>(&self, fmt: &mut F) -> Result<Sql, FormatError>
+    where
+        F: fmt::Formatter,
+    {
+        let argument = match self.argument.as_ref() {
+            Some(argument) => Some(argument.format(fmt)?),
+            None => None,
+        };
+        fmt.expression()
+            .aggregate(&self.function, argument, self.distinct)
+    }
+}
+
+impl Fields for Aggregate {
+    type FieldIter = std::iter::Once<String>;
+
+    fn to_iterator(&self) -> Self::FieldIter {
+        std::iter::once(self.name())
+    }
+
+    fn columns(&self) -> Vec<String> {
+        self.argument.iter().flat_map(|a| a.columns()).collect()
+    }
+
+    fn ungrouped_columns(&self) -> Vec<String> {
+        Vec::new()
+    }
+
+    fn contains_aggregate(&self) -> bool {
+        true
+    }
+
+    fn format<F>(&self, fmt: &mut F) -> Result<Sql, FormatError>
+    where
+        F: fmt::Formatter,
+    {
+        let call = self.format_call(fmt)?;
+
+        let mut fields = fmt.fields();
+        match self.alias.as_ref() {
+            Some(alias) => fields.add_field(format!("{} AS {}", call, alias)),
+            None => fields.add_field(call.into()),
+        };
+        fields.finish()
+    }
+}
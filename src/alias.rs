@@ -0,0 +1,75 @@
+use std::collections::HashSet;
+
+use crate::{
+    fmt::FormatError,
+    sql::Sql,
+    verify::{RootTableDefinitions, Scope, VerifyError, VerifyTable},
+    Table,
+};
+
+/// Gives a Table a new Name, which can then be used to refer to its Fields
+pub struct Aliased<T>
+where
+    T: Table,
+{
+    table: T,
+    alias: String,
+}
+
+impl<T> Aliased<T>
+where
+    T: Table,
+{
+    /// Creates a new Alias for the given Table
+    pub fn new<N>(table: T, alias: N) -> Self
+    where
+        N: Into<String>,
+    {
+        Self {
+            table,
+            alias: alias.into(),
+        }
+    }
+}
+
+impl<T> Table for Aliased<T>
+where
+    T: Table,
+{
+    fn format<F>(&self, fmt: &mut F) -> Result<Sql, FormatError>
+    where
+        F: crate::fmt::Formatter,
+    {
+        let table_str = self.table.format(fmt)?;
+        // The Alias would otherwise only apply to the last Table of the Join
+        match self.table.is_join() {
+            true => Ok(Sql::new(format!("({}) AS {}", table_str, self.alias))),
+            false => Ok(Sql::new(format!("{} AS {}", table_str, self.alias))),
+        }
+    }
+}
+
+impl<T> VerifyTable for Aliased<T>
+where
+    T: VerifyTable,
+{
+    fn get_fields(&self, roots: &RootTableDefinitions) -> Result<HashSet<String>, VerifyError> {
+        let inner = self.table.get_fields(roots)?;
+
+        // The Alias hides the original Name of the Table, so only the unqualified Fields are kept
+        Ok(crate::verify::qualify(
+            &self.alias,
+            inner
+                .into_iter()
+                .filter(|f| !(crate::verify::is_identifier(f) && f.contains('.'))),
+        ))
+    }
+
+    fn get_columns_scoped(
+        &self,
+        roots: &RootTableDefinitions,
+        _: Option<&Scope<'_>>,
+    ) -> Result<Vec<String>, VerifyError> {
+        self.table.get_columns(roots)
+    }
+}
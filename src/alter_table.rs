@@ -0,0 +1,284 @@
+use crate::{
+    fmt::{AlterTableBuilder, FmtBuilder, FormatError},
+    sql::{Sql, Types},
+    Column, Constraint, CreateTable, Statement, Statements,
+};
+
+/// A single Change to the Structure of a Table
+#[derive(Debug, Clone, PartialEq)]
+pub enum AlterOperation {
+    /// Adds a new Column with the given Name and Type
+    AddColumn(String, Types),
+    /// Removes the Column
+    DropColumn(String),
+    /// Renames the Column
+    RenameColumn {
+        /// The current Name of the Column
+        from: String,
+        /// The new Name of the Column
+        to: String,
+    },
+    /// Changes the Type of the Column
+    AlterColumnType(String, Types),
+    /// Renames the Table itself
+    RenameTable(String),
+    /// Adds a new Constraint
+    AddConstraint(Constraint),
+    /// Removes the Constraint with the given Name
+    DropConstraint(String),
+}
+
+/// Changes the Structure of an existing Table.
+///
+/// Some Dialects, like SQLite, can't perform every Operation in place, but instead have to create
+/// a new Table with the changed Structure and copy over all the Rows. This requires the current
+/// Definition of the Table, which can be supplied using [`AlterTable::definition`]. The Indexes
+/// and Triggers of the old Table are dropped together with it, so they have to be recreated using
+/// [`AlterTable::restore`].
+pub struct AlterTable<R = ()>
+where
+    R: Statements,
+{
+    table: String,
+    operations: Vec<AlterOperation>,
+    definition: Option<CreateTable>,
+    foreign_keys: bool,
+    restore: R,
+}
+
+impl AlterTable {
+    /// Creates a new empty Alteration of the Table
+    pub fn new<N>(table: N) -> Self
+    where
+        N: Into<String>,
+    {
+        Self {
+            table: table.into(),
+            operations: Vec::new(),
+            definition: None,
+            foreign_keys: false,
+            restore: (),
+        }
+    }
+}
+
+impl<R> AlterTable<R>
+where
+    R: Statements,
+{
+    /// Adds the Operation to the Alteration
+    pub fn operation(mut self, operation: AlterOperation) -> Self {
+        self.operations.push(operation);
+        self
+    }
+
+    /// Adds a new Column
+    pub fn add_column<N>(self, name: N, ty: Types) -> Self
+    where
+        N: Into<String>,
+    {
+        self.operation(AlterOperation::AddColumn(name.into(), ty))
+    }
+
+    /// Removes the Column
+    pub fn drop_column<N>(self, name: N) -> Self
+    where
+        N: Into<String>,
+    {
+        self.operation(AlterOperation::DropColumn(name.into()))
+    }
+
+    /// Renames the Column
+    pub fn rename_column<F, T>(self, from: F, to: T) -> Self
+    where
+        F: Into<String>,
+        T: Into<String>,
+    {
+        self.operation(AlterOperation::RenameColumn {
+            from: from.into(),
+            to: to.into(),
+        })
+    }
+
+    /// Changes the Type of the Column
+    pub fn alter_column_type<N>(self, name: N, ty: Types) -> Self
+    where
+        N: Into<String>,
+    {
+        self.operation(AlterOperation::AlterColumnType(name.into(), ty))
+    }
+
+    /// Renames the Table
+    pub fn rename_to<N>(self, name: N) -> Self
+    where
+        N: Into<String>,
+    {
+        self.operation(AlterOperation::RenameTable(name.into()))
+    }
+
+    /// Adds a new Constraint
+    pub fn add_constraint(self, constraint: Constraint) -> Self {
+        self.operation(AlterOperation::AddConstraint(constraint))
+    }
+
+    /// Removes the Constraint with the given Name
+    pub fn drop_constraint<N>(self, name: N) -> Self
+    where
+        N: Into<String>,
+    {
+        self.operation(AlterOperation::DropConstraint(name.into()))
+    }
+
+    /// The current Definition of the Table, which is needed for Operations that can only be
+    /// emulated by rebuilding the Table
+    pub fn definition(mut self, definition: CreateTable) -> Self {
+        self.definition = Some(definition);
+        self
+    }
+
+    /// Declares that Foreign Keys are enforced on the Connection, which are then turned off while
+    /// the Table is rebuilt and turned back on afterwards, so that dropping the old Table does not
+    /// trigger any `ON DELETE` Actions. SQLite ignores this inside of a Transaction, so the
+    /// Statement then has to run outside of one.
+    pub fn foreign_keys_enabled(mut self) -> Self {
+        self.foreign_keys = true;
+        self
+    }
+
+    /// The Statements, like [`CreateIndex`](crate::CreateIndex), that recreate the Indexes,
+    /// Triggers and Views of the Table after it has been rebuilt. They are left out if the Table
+    /// can be altered in place, as it then keeps them.
+    pub fn restore<S>(self, statements: S) -> AlterTable<S>
+    where
+        S: Statements,
+    {
+        AlterTable {
+            table: self.table,
+            operations: self.operations,
+            definition: self.definition,
+            foreign_keys: self.foreign_keys,
+            restore: statements,
+        }
+    }
+}
+
+impl<R> Statement for AlterTable<R>
+where
+    R: Statements,
+{
+    fn format<F>(&self, fmt: &mut F) -> Result<Sql, FormatError>
+    where
+        F: crate::fmt::Formatter,
+    {
+        let mut restore = fmt.script();
+        self.restore.add_to(&mut restore);
+        let restore = restore.finish()?;
+
+        fmt.alter_table()
+            .table(&self.table)
+            .operations(&self.operations)
+            .definition(self.definition.as_ref())
+            .foreign_keys(self.foreign_keys)
+            .restore(restore)
+            .finish()
+    }
+}
+
+/// Applies the Operations to the Definition of a Table, which returns the new Definition and the
+/// Pairs of (new, old) Names for the Columns whose Values should be copied over
+pub(crate) fn rebuild(
+    definition: &CreateTable,
+    operations: &[AlterOperation],
+) -> Result<(CreateTable, Vec<(String, String)>), FormatError> {
+    let mut name = definition.name().to_string();
+    let mut columns = definition.column_definitions().to_vec();
+    let mut constraints = definition.constraints().to_vec();
+    // Generated Columns are computed by the new Table itself
+    let mut copied: Vec<(String, String)> = columns
+        .iter()
+        .filter(|c| c.generation().is_none())
+        .map(|c| (c.name().to_string(), c.name().to_string()))
+        .collect();
+
+    for operation in operations {
+        let target = match operation {
+            AlterOperation::DropColumn(column)
+            | AlterOperation::RenameColumn { from: column, .. }
+            | AlterOperation::AlterColumnType(column, _) => Some(column),
+            _ => None,
+        };
+        if let Some(column) = target.filter(|t| columns.iter().all(|c| c.name() != *t)) {
+            return Err(FormatError::Invalid {
+                reason: format!("The Column {} does not exist", column),
+            });
+        }
+
+        match operation {
+            AlterOperation::AddColumn(column, ty) => columns.push(Column::new(column, *ty)),
+            AlterOperation::DropColumn(column) => {
+                // Like SQLite itself, Columns that are still used can't be dropped
+                let constraint = constraints
+                    .iter()
+                    .find(|c| c.columns().contains(column))
+                    .map(|c| match c.name() {
+                        Some(name) => format!("the Constraint {}", name),
+                        None => "a Constraint".to_string(),
+                    });
+                let generated = columns
+                    .iter()
+                    .filter(|c| c.name() != column)
+                    .find(
+                        |c| matches!(c.generation(), Some((e, _)) if e.columns().contains(column)),
+                    )
+                    .map(|c| format!("the generated Column {}", c.name()));
+                if let Some(user) = constraint.or(generated) {
+                    return Err(FormatError::Invalid {
+                        reason: format!("The Column {} is still used by {}", column, user),
+                    });
+                }
+
+                columns.retain(|c| c.name() != column);
+                copied.retain(|(n, _)| n != column);
+            }
+            AlterOperation::RenameColumn { from, to } => {
+                for c in columns.iter_mut() {
+                    c.rename_column(from, to);
+                }
+                for c in constraints.iter_mut() {
+                    c.rename_column(from, to);
+                }
+                for (n, _) in copied.iter_mut().filter(|(n, _)| n == from) {
+                    *n = to.clone();
+                }
+            }
+            AlterOperation::AlterColumnType(column, ty) => {
+                for c in columns.iter_mut().filter(|c| c.name() == column) {
+                    c.set_ty(*ty);
+                }
+            }
+            AlterOperation::RenameTable(new_name) => name = new_name.clone(),
+            AlterOperation::AddConstraint(constraint) => constraints.push(constraint.clone()),
+            AlterOperation::DropConstraint(constraint) => {
+                let previous = constraints.len();
+                constraints.retain(|c| c.name() != Some(constraint.as_str()));
+                if constraints.len() == previous {
+                    return Err(FormatError::Invalid {
+                        reason: format!("The Constraint {} does not exist", constraint),
+                    });
+                }
+            }
+        }
+    }
+
+    let mut result = CreateTable::new(name, columns);
+    for constraint in constraints {
+        result = result.constraint(constraint);
+    }
+    if definition.is_strict() {
+        result = result.strict();
+    }
+    if definition.is_without_rowid() {
+        result = result.without_rowid();
+    }
+    Ok((result, copied))
+}
@@ -0,0 +1,226 @@
+use crate::{
+    fmt::{self, FmtBuilder, FormatError, InsertBuilder},
+    sql::Sql,
+    Expression, OnConflict, Table,
+};
+
+/// Inserts many Rows into a Table, which are split into as few Statements as possible, while
+/// staying below the [`Limits`](crate::fmt::Limits) of the Formatter
+pub struct BatchInsert<T, I>
+where
+    T: Table,
+    I: Iterator<Item = Vec<Expression>>,
+{
+    table: T,
+    columns: Vec<String>,
+    rows: I,
+    conflict: Option<OnConflict>,
+}
+
+impl<T, I> BatchInsert<T, I>
+where
+    T: Table,
+    I: Iterator<Item = Vec<Expression>>,
+{
+    /// Creates a new Batch, where every Row contains the Values for the given Columns in Order
+    pub fn new<C, N, R>(table: T, columns: C, rows: R) -> Self
+    where
+        C: IntoIterator<Item = N>,
+        N: Into<String>,
+        R: IntoIterator<Item = Vec<Expression>, IntoIter = I>,
+    {
+        Self {
+            table,
+            columns: columns.into_iter().map(|c| c.into()).collect(),
+            rows: rows.into_iter(),
+            conflict: None,
+        }
+    }
+
+    /// Handles Conflicts with already existing Rows, see [`OnConflict`]
+    pub fn on_conflict(mut self, conflict: OnConflict) -> Self {
+        self.conflict = Some(conflict);
+        self
+    }
+
+    /// Formats the Rows lazily into a Sequence of Insert Statements using the Formatter
+    pub fn format<F>(self, fmt: &mut F) -> Batches<'_, T, I, F>
+    where
+        F: fmt::Formatter,
+    {
+        Batches {
+            insert: self,
+            fmt,
+            overhead: None,
+            pending: None,
+            done: false,
+        }
+    }
+}
+
+/// A single Row, that has already been formatted
+struct FormattedRow {
+    values: Vec<Sql>,
+    parameters: usize,
+    length: usize,
+}
+
+/// The Sequence of Insert Statements for a [`BatchInsert`]
+pub struct Batches<'f, T, I, F>
+where
+    T: Table,
+    I: Iterator<Item = Vec<Expression>>,
+    F: fmt::Formatter,
+{
+    insert: BatchInsert<T, I>,
+    fmt: &'f mut F,
+    /// The Parameters and Length of a Statement without any Rows
+    overhead: Option<(usize, usize)>,
+    /// The Row that did not fit into the previous Statement anymore
+    pending: Option<FormattedRow>,
+    done: bool,
+}
+
+impl<'f, T, I, F> Batches<'f, T, I, F>
+where
+    T: Table,
+    I: Iterator<Item = Vec<Expression>>,
+    F: fmt::Formatter,
+{
+    fn overhead(&mut self) -> Result<(usize, usize), FormatError> {
+        if let Some(overhead) = self.overhead {
+            return Ok(overhead);
+        }
+
+        let parameters = match self.insert.conflict.as_ref() {
+            Some(conflict) => {
+                let assignments = conflict.assignments().iter().map(|(_, v)| v.parameters());
+                let condition = conflict.update_condition().map(|c| c.parameters());
+                assignments.chain(condition).sum()
+            }
+            None => 0,
+        };
+        let empty = self
+            .fmt
+            .insert()
+            .table(&self.insert.table)
+            .rows(&self.insert.columns, vec![Vec::new()])
+            .on_conflict(self.insert.conflict.as_ref())
+            .finish()?;
+
+        let overhead = (parameters, empty.to_string().len());
+        self.overhead = Some(overhead);
+        Ok(overhead)
+    }
+
+    fn next_row(&mut self) -> Option<Result<FormattedRow, FormatError>> {
+        if let Some(row) = self.pending.take() {
+            return Some(Ok(row));
+        }
+
+        let row = self.insert.rows.next()?;
+        if row.len() != self.insert.columns.len() {
+            return Some(Err(FormatError::RowLength {
+                expected: self.insert.columns.len(),
+                found: row.len(),
+            }));
+        }
+
+        let mut values = Vec::with_capacity(row.len());
+        let mut parameters = 0;
+        // The Parentheses and the Separators between the Values and the Rows
+        let mut length = row.len() + 2;
+        for value in row.iter() {
+            let value_sql = match value.format(self.fmt) {
+                Ok(v) => v,
+                Err(e) => return Some(Err(e)),
+            };
+            parameters += value.parameters();
+            length += value_sql.to_string().len();
+            values.push(value_sql);
+        }
+
+        Some(Ok(FormattedRow {
+            values,
+            parameters,
+            length,
+        }))
+    }
+
+    fn next_statement(&mut self) -> Result<Option<Sql>, FormatError> {
+        // Without Columns the Rows would turn into DEFAULT VALUES, which drops their Values
+        if self.insert.columns.is_empty() {
+            return Err(FormatError::Invalid {
+                reason: "A Batch needs at least one Column".to_string(),
+            });
+        }
+
+        let limits = self.fmt.limits();
+        let (mut parameters, mut length) = self.overhead()?;
+
+        let mut rows = Vec::new();
+        while let Some(row) = self.next_row() {
+            let row = row?;
+
+            let fits = parameters + row.parameters <= limits.parameters
+                && length + row.length <= limits.length;
+            if !fits {
+                if rows.is_empty() {
+                    let limit = if parameters + row.parameters > limits.parameters {
+                        format!("{} Parameters", limits.parameters)
+                    } else {
+                        format!("{} Bytes", limits.length)
+                    };
+                    return Err(FormatError::LimitExceeded { limit });
+                }
+
+                self.pending = Some(row);
+                break;
+            }
+
+            parameters += row.parameters;
+            length += row.length;
+            rows.push(row.values);
+        }
+
+        if rows.is_empty() {
+            return Ok(None);
+        }
+
+        self.fmt
+            .insert()
+            .table(&self.insert.table)
+            .rows(&self.insert.columns, rows)
+            .on_conflict(self.insert.conflict.as_ref())
+            .finish()
+            .map(Some)
+    }
+}
+
+impl<'f, T, I, F> Iterator for Batches<'f, T, I, F>
+where
+    T: Table,
+    I: Iterator<Item = Vec<Expression>>,
+    F: fmt::Formatter,
+{
+    type Item = Result<Sql, FormatError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        match self.next_statement() {
+            Ok(Some(statement)) => Some(Ok(statement)),
+            Ok(None) => {
+                self.done = true;
+                None
+            }
+            Err(e) => {
+                // The remaining Rows can't be formatted consistently after an Error
+                self.done = true;
+                Some(Err(e))
+            }
+        }
+    }
+}
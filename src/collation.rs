@@ -0,0 +1,23 @@
+/// Determines how Strings are compared and sorted
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Collation {
+    /// Compares the Bytes of the Strings
+    Binary,
+    /// Ignores the Case of ASCII-Letters
+    NoCase,
+    /// Ignores trailing Spaces
+    RTrim,
+    /// Any other Collation known to the Database, like an ICU Collation in PostgreSQL or a custom
+    /// Collation registered in SQLite
+    Named(String),
+}
+
+impl Collation {
+    /// Creates a new Reference to a Collation known to the Database
+    pub fn named<N>(name: N) -> Self
+    where
+        N: Into<String>,
+    {
+        Self::Named(name.into())
+    }
+}
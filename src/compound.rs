@@ -0,0 +1,161 @@
+use std::collections::HashSet;
+
+use crate::{
+    fmt::{CompoundBuilder, FmtBuilder, FormatError},
+    sql::Sql,
+    verify::{RootTableDefinitions, Scope, VerifyError, VerifyTable},
+    Statement, Table,
+};
+
+/// The Operators used to combine the Results of two Selects
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SetOperator {
+    /// All the distinct Rows from both sides
+    Union,
+    /// All the Rows from both sides, including duplicates
+    UnionAll,
+    /// The distinct Rows that are returned by both sides
+    Intersect,
+    /// The distinct Rows of the left side that are not returned by the right side
+    Except,
+}
+
+/// Combines the Results of two Selects using a [`SetOperator`], the resulting Fields are named
+/// after the left side.
+///
+/// Compounds can be chained further, but only on the left side, as most Dialects don't allow
+/// nesting them on the right side.
+pub struct Compound<L, R>
+where
+    L: Statement,
+    R: Statement,
+{
+    left: L,
+    operator: SetOperator,
+    right: R,
+}
+
+impl<L, R> Compound<L, R>
+where
+    L: Statement,
+    R: Statement,
+{
+    /// Creates a new Compound of the two sides
+    pub fn new(left: L, operator: SetOperator, right: R) -> Self {
+        Self {
+            left,
+            operator,
+            right,
+        }
+    }
+
+    /// Combines the current Compound with another Select using UNION
+    pub fn union<O>(self, other: O) -> Compound<Self, O>
+    where
+        O: Statement,
+    {
+        Compound::new(self, SetOperator::Union, other)
+    }
+
+    /// Combines the current Compound with another Select using UNION ALL
+    pub fn union_all<O>(self, other: O) -> Compound<Self, O>
+    where
+        O: Statement,
+    {
+        Compound::new(self, SetOperator::UnionAll, other)
+    }
+
+    /// Combines the current Compound with another Select using INTERSECT
+    pub fn intersect<O>(self, other: O) -> Compound<Self, O>
+    where
+        O: Statement,
+    {
+        Compound::new(self, SetOperator::Intersect, other)
+    }
+
+    /// Combines the current Compound with another Select using EXCEPT
+    pub fn except<O>(self, other: O) -> Compound<Self, O>
+    where
+        O: Statement,
+    {
+        Compound::new(self, SetOperator::Except, other)
+    }
+}
+
+impl<L, R> Statement for Compound<L, R>
+where
+    L: Statement,
+    R: Statement,
+{
+    fn format<F>(&self, fmt: &mut F) -> Result<Sql, FormatError>
+    where
+        F: crate::fmt::Formatter,
+    {
+        fmt.compound()
+            .left(&self.left)
+            .operator(self.operator)
+            .right(&self.right)
+            .finish()
+    }
+}
+impl<L, R> Table for Compound<L, R>
+where
+    L: Statement,
+    R: Statement,
+{
+    fn format<F>(&self, fmt: &mut F) -> Result<Sql, FormatError>
+    where
+        F: crate::fmt::Formatter,
+    {
+        let inner = Statement::format(self, fmt)?;
+        Ok(Sql::new(format!("({})", inner)))
+    }
+}
+
+impl<L, R> VerifyTable for Compound<L, R>
+where
+    L: Statement + VerifyTable,
+    R: Statement + VerifyTable,
+{
+    fn get_fields(&self, roots: &RootTableDefinitions) -> Result<HashSet<String>, VerifyError> {
+        self.get_fields_scoped(roots, None)
+    }
+
+    fn get_fields_scoped(
+        &self,
+        roots: &RootTableDefinitions,
+        outer: Option<&Scope<'_>>,
+    ) -> Result<HashSet<String>, VerifyError> {
+        self.get_columns_scoped(roots, outer)?;
+        self.left.get_fields_scoped(roots, outer)
+    }
+
+    fn get_anchor_fields(
+        &self,
+        roots: &RootTableDefinitions,
+    ) -> Result<HashSet<String>, VerifyError> {
+        self.left.get_anchor_fields(roots)
+    }
+
+    fn get_anchor_columns(&self, roots: &RootTableDefinitions) -> Result<Vec<String>, VerifyError> {
+        self.left.get_anchor_columns(roots)
+    }
+
+    fn get_columns_scoped(
+        &self,
+        roots: &RootTableDefinitions,
+        outer: Option<&Scope<'_>>,
+    ) -> Result<Vec<String>, VerifyError> {
+        let left = self.left.get_columns_scoped(roots, outer)?;
+        let right = self.right.get_columns_scoped(roots, outer)?;
+
+        if left.len() != right.len() {
+            return Err(VerifyError::ColumnCountMismatch {
+                expected: left.len(),
+                found: right.len(),
+            });
+        }
+
+        Ok(left)
+    }
+}
@@ -1,15 +1,38 @@
 use crate::{
-    fmt::{self, ConditionBuilder},
+    fmt::{self, ConditionBuilder, FormatError},
     sql::Sql,
+    verify::{self, RootTableDefinitions, Scope, VerifyCondition, VerifyError},
     Condition,
 };
 
+/// The Operators for comparing two Values
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Comparison {
+    /// `=`
+    Equal,
+    /// `<>`
+    NotEqual,
+    /// `<`
+    Less,
+    /// `<=`
+    LessEqual,
+    /// `>`
+    Greater,
+    /// `>=`
+    GreaterEqual,
+}
+
 impl Condition for () {
-    fn format<F>(&self, _: &mut F) -> Option<Sql>
+    fn format<F>(&self, _: &mut F) -> Result<Option<Sql>, FormatError>
     where
         F: fmt::Formatter,
     {
-        None
+        Ok(None)
+    }
+}
+impl VerifyCondition for () {
+    fn verify(&self, _: &Scope<'_>, _: &RootTableDefinitions) -> Result<(), VerifyError> {
+        Ok(())
     }
 }
 
@@ -18,14 +41,30 @@ where
     S1: AsRef<str>,
     S2: AsRef<str>,
 {
-    fn format<F>(&self, fmt: &mut F) -> Option<Sql>
+    fn format<F>(&self, fmt: &mut F) -> Result<Option<Sql>, FormatError>
     where
         F: fmt::Formatter,
     {
         let left = Sql::new(self.0.as_ref());
         let right = Sql::new(self.1.as_ref());
 
-        Some(fmt.condition().equal(left, right))
+        Ok(Some(fmt.condition().equal(left, right)))
+    }
+}
+impl<S1, S2> VerifyCondition for (S1, S2)
+where
+    S1: AsRef<str>,
+    S2: AsRef<str>,
+{
+    fn verify(&self, scope: &Scope<'_>, _: &RootTableDefinitions) -> Result<(), VerifyError> {
+        // Only plain Identifiers refer to Columns, everything else is either a Literal or some
+        // other Expression
+        for side in [self.0.as_ref(), self.1.as_ref()] {
+            if verify::is_identifier(side) {
+                scope.resolve(side)?;
+            }
+        }
+        Ok(())
     }
 }
 
@@ -55,14 +94,27 @@ where
     L: Condition,
     R: Condition,
 {
-    fn format<F>(&self, fmt: &mut F) -> Option<Sql>
+    fn format<F>(&self, fmt: &mut F) -> Result<Option<Sql>, FormatError>
     where
         F: fmt::Formatter,
     {
         let left_str = self.left.format(fmt)?;
         let right_str = self.right.format(fmt)?;
 
-        Some(fmt.condition().and(left_str, right_str))
+        match (left_str, right_str) {
+            (Some(left), Some(right)) => Ok(Some(fmt.condition().and(left, right))),
+            _ => Ok(None),
+        }
+    }
+}
+impl<L, R> VerifyCondition for And<L, R>
+where
+    L: VerifyCondition,
+    R: VerifyCondition,
+{
+    fn verify(&self, scope: &Scope<'_>, roots: &RootTableDefinitions) -> Result<(), VerifyError> {
+        self.left.verify(scope, roots)?;
+        self.right.verify(scope, roots)
     }
 }
 
@@ -92,13 +144,26 @@ where
     L: Condition,
     R: Condition,
 {
-    fn format<F>(&self, fmt: &mut F) -> Option<Sql>
+    fn format<F>(&self, fmt: &mut F) -> Result<Option<Sql>, FormatError>
     where
         F: fmt::Formatter,
     {
         let left_str = self.left.format(fmt)?;
         let right_str = self.right.format(fmt)?;
 
-        Some(fmt.condition().or(left_str, right_str))
+        match (left_str, right_str) {
+            (Some(left), Some(right)) => Ok(Some(fmt.condition().or(left, right))),
+            _ => Ok(None),
+        }
+    }
+}
+impl<L, R> VerifyCondition for Or<L, R>
+where
+    L: VerifyCondition,
+    R: VerifyCondition,
+{
+    fn verify(&self, scope: &Scope<'_>, roots: &RootTableDefinitions) -> Result<(), VerifyError> {
+        self.left.verify(scope, roots)?;
+        self.right.verify(scope, roots)
     }
 }
@@ -0,0 +1,124 @@
+use crate::{
+    fmt::{CreateIndexBuilder, FmtBuilder, FormatError},
+    sql::Sql,
+    verify::{IndexDefinition, RootTableDefinitions, Scope, VerifyCondition, VerifyError},
+    Condition, OrderBy, Statement,
+};
+
+/// Creates a new Index on a Table.
+///
+/// Every Key is either a plain Column or an arbitrary Expression together with its Sort Order,
+/// an optional Condition turns it into a partial Index, which only contains the matching Rows.
+pub struct CreateIndex<C = ()>
+where
+    C: Condition,
+{
+    name: String,
+    table: String,
+    unique: bool,
+    keys: Vec<OrderBy>,
+    condition: C,
+    partial: bool,
+}
+
+impl CreateIndex {
+    /// Creates a new Index with the given Name on the Table using the Keys in Order
+    pub fn new<N, T, I>(name: N, table: T, keys: I) -> Self
+    where
+        N: Into<String>,
+        T: Into<String>,
+        I: IntoIterator<Item = OrderBy>,
+    {
+        Self {
+            name: name.into(),
+            table: table.into(),
+            unique: false,
+            keys: keys.into_iter().collect(),
+            condition: (),
+            partial: false,
+        }
+    }
+}
+
+impl<C> CreateIndex<C>
+where
+    C: Condition,
+{
+    /// Enforces that no two Rows have the same Values for the Keys
+    pub fn unique(mut self) -> Self {
+        self.unique = true;
+        self
+    }
+
+    /// Only includes the Rows matching the Condition in the Index
+    pub fn condition<C2>(self, condition: C2) -> CreateIndex<C2>
+    where
+        C2: Condition,
+    {
+        CreateIndex {
+            name: self.name,
+            table: self.table,
+            unique: self.unique,
+            keys: self.keys,
+            condition,
+            partial: true,
+        }
+    }
+
+    /// The Name of the Index
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The Definition of the Index, which can be recorded using
+    /// [`RootTableDefinitions::add_index`]
+    pub fn definition(&self) -> IndexDefinition {
+        IndexDefinition {
+            table: self.table.clone(),
+            columns: self.keys.iter().flat_map(|k| k.columns()).collect(),
+            unique: self.unique,
+            partial: self.partial,
+        }
+    }
+}
+
+impl<C> CreateIndex<C>
+where
+    C: VerifyCondition,
+{
+    /// Verifies the Index against its Table and then records its Definition
+    pub fn register(&self, roots: &mut RootTableDefinitions) -> Result<(), VerifyError> {
+        let fields = roots
+            .get_table(&self.table)
+            .ok_or_else(|| VerifyError::UnknownTable {
+                table: self.table.clone(),
+            })?;
+        let scope = Scope::new(fields);
+
+        for column in self.keys.iter().flat_map(|k| k.columns()) {
+            scope.resolve(&column)?;
+        }
+        self.condition.verify(&scope, roots)?;
+
+        roots.add_index(self.name.clone(), self.definition());
+        Ok(())
+    }
+}
+
+impl<C> Statement for CreateIndex<C>
+where
+    C: Condition,
+{
+    fn format<F>(&self, fmt: &mut F) -> Result<Sql, FormatError>
+    where
+        F: crate::fmt::Formatter,
+    {
+        fmt.create_index()
+            .name(&self.name)
+            .table(&self.table)
+            .unique(self.unique)
+            .keys(&self.keys)
+            .condition(&self.condition)
+            .finish()
+    }
+}
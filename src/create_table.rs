@@ -1,39 +1,337 @@
 use std::collections::HashSet;
 
-use crate::{sql::Types, Statement};
+use crate::{
+    fmt::{CreateTableBuilder, FmtBuilder},
+    sql::Types,
+    Collation, Expression, Statement,
+};
+
+/// The Kinds of Constraints that can be placed on the Rows of a Table
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConstraintKind {
+    /// The Columns uniquely identify a Row
+    PrimaryKey(Vec<String>),
+    /// The Combination of the Columns is unique across all Rows
+    Unique(Vec<String>),
+    /// The Columns refer to the Columns of a Row in another Table
+    ForeignKey {
+        /// The Columns of this Table
+        columns: Vec<String>,
+        /// The referenced Table
+        table: String,
+        /// The referenced Columns of the other Table
+        references: Vec<String>,
+    },
+    /// The Condition holds for every Row
+    Check(Expression),
+}
+
+/// A Constraint on the Rows of a Table
+#[derive(Debug, Clone, PartialEq)]
+pub struct Constraint {
+    name: Option<String>,
+    kind: ConstraintKind,
+}
+
+impl Constraint {
+    /// Creates a new unnamed Constraint
+    pub fn new(kind: ConstraintKind) -> Self {
+        Self { name: None, kind }
+    }
+
+    /// `PRIMARY KEY (...)`
+    pub fn primary_key<I, N>(columns: I) -> Self
+    where
+        I: IntoIterator<Item = N>,
+        N: Into<String>,
+    {
+        Self::new(ConstraintKind::PrimaryKey(
+            columns.into_iter().map(|c| c.into()).collect(),
+        ))
+    }
+
+    /// `UNIQUE (...)`
+    pub fn unique<I, N>(columns: I) -> Self
+    where
+        I: IntoIterator<Item = N>,
+        N: Into<String>,
+    {
+        Self::new(ConstraintKind::Unique(
+            columns.into_iter().map(|c| c.into()).collect(),
+        ))
+    }
+
+    /// `FOREIGN KEY (...) REFERENCES table (...)`
+    pub fn foreign_key<I, N, T, R, M>(columns: I, table: T, references: R) -> Self
+    where
+        I: IntoIterator<Item = N>,
+        N: Into<String>,
+        T: Into<String>,
+        R: IntoIterator<Item = M>,
+        M: Into<String>,
+    {
+        Self::new(ConstraintKind::ForeignKey {
+            columns: columns.into_iter().map(|c| c.into()).collect(),
+            table: table.into(),
+            references: references.into_iter().map(|c| c.into()).collect(),
+        })
+    }
+
+    /// `CHECK (...)`
+    pub fn check(condition: Expression) -> Self {
+        Self::new(ConstraintKind::Check(condition))
+    }
+
+    /// Gives the Constraint a Name, so it can be referred to later on
+    pub fn named<N>(mut self, name: N) -> Self
+    where
+        N: Into<String>,
+    {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// The Name of the Constraint, if any
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// The Kind of the Constraint
+    pub fn kind(&self) -> &ConstraintKind {
+        &self.kind
+    }
+
+    /// The Columns of the Table used by the Constraint
+    pub(crate) fn columns(&self) -> Vec<String> {
+        match &self.kind {
+            ConstraintKind::PrimaryKey(columns)
+            | ConstraintKind::Unique(columns)
+            | ConstraintKind::ForeignKey { columns, .. } => columns.clone(),
+            ConstraintKind::Check(condition) => condition.columns(),
+        }
+    }
+
+    /// Replaces all the References to the Column of the Table with its new Name
+    pub(crate) fn rename_column(&mut self, from: &str, to: &str) {
+        match &mut self.kind {
+            ConstraintKind::PrimaryKey(columns)
+            | ConstraintKind::Unique(columns)
+            | ConstraintKind::ForeignKey { columns, .. } => {
+                for column in columns.iter_mut().filter(|c| *c == from) {
+                    *column = to.to_string();
+                }
+            }
+            ConstraintKind::Check(condition) => condition.rename_column(from, to),
+        }
+    }
+}
+
+/// How the Value of a generated Column is stored
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Generated {
+    /// The Value is computed when the Row is written and stored alongside the other Columns
+    Stored,
+    /// The Value is computed every Time the Row is read
+    Virtual,
+}
+
+/// The Definition of a single Column of a Table
+#[derive(Debug, Clone, PartialEq)]
+pub struct Column {
+    name: String,
+    ty: Types,
+    primary_key: bool,
+    autoincrement: bool,
+    generated: Option<(Expression, Generated)>,
+    collation: Option<Collation>,
+}
+
+impl Column {
+    /// Creates a new plain Column
+    pub fn new<N>(name: N, ty: Types) -> Self
+    where
+        N: Into<String>,
+    {
+        Self {
+            name: name.into(),
+            ty,
+            primary_key: false,
+            autoincrement: false,
+            generated: None,
+            collation: None,
+        }
+    }
+
+    /// Makes the Column the Primary Key of the Table
+    pub fn primary_key(mut self) -> Self {
+        self.primary_key = true;
+        self
+    }
+
+    /// Makes the Column the Primary Key of the Table, whose Values are never reused, even after
+    /// the Row was deleted. This is only supported for Integer Columns by SQLite
+    pub fn autoincrement(mut self) -> Self {
+        self.primary_key = true;
+        self.autoincrement = true;
+        self
+    }
+
+    /// Computes the Value of the Column from the other Columns of the Row
+    pub fn generated(mut self, expression: Expression, storage: Generated) -> Self {
+        self.generated = Some((expression, storage));
+        self
+    }
+
+    /// Uses the Collation for comparing and sorting the Values of the Column by Default
+    pub fn collate(mut self, collation: Collation) -> Self {
+        self.collation = Some(collation);
+        self
+    }
+
+    /// The Name of the Column
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The Type of the Column
+    pub fn ty(&self) -> Types {
+        self.ty
+    }
+
+    /// Whether or not the Column is the Primary Key of the Table
+    pub fn is_primary_key(&self) -> bool {
+        self.primary_key
+    }
+
+    /// Whether or not the Values of the Primary Key are never reused
+    pub fn is_autoincrement(&self) -> bool {
+        self.autoincrement
+    }
+
+    /// The Expression and Storage of a generated Column
+    pub fn generation(&self) -> Option<(&Expression, Generated)> {
+        self.generated.as_ref().map(|(e, g)| (e, *g))
+    }
+
+    /// The default Collation of the Column
+    pub fn collation(&self) -> Option<&Collation> {
+        self.collation.as_ref()
+    }
+
+    pub(crate) fn set_ty(&mut self, ty: Types) {
+        self.ty = ty;
+    }
+
+    /// Renames the Column itself and all the References to it inside of the generating Expression
+    pub(crate) fn rename_column(&mut self, from: &str, to: &str) {
+        if self.name == from {
+            self.name = to.to_string();
+        }
+        if let Some((expression, _)) = self.generated.as_mut() {
+            expression.rename_column(from, to);
+        }
+    }
+}
+
+impl<N> From<(N, Types)> for Column
+where
+    N: Into<String>,
+{
+    fn from((name, ty): (N, Types)) -> Self {
+        Self::new(name, ty)
+    }
+}
 
 /// Creates a new Table with the given Configuration
+#[derive(Debug, Clone, PartialEq)]
 pub struct CreateTable {
     name: String,
-    /// A List of (Name, Type) Tuples representing the Columns
-    columns: Vec<(String, Types)>,
+    columns: Vec<Column>,
+    constraints: Vec<Constraint>,
+    strict: bool,
+    without_rowid: bool,
 }
 
 impl CreateTable {
-    /// Creates a new CreateTable Expression
-    pub fn new<N, C>(name: N, columns: C) -> Self
+    /// Creates a new CreateTable Expression, the Columns can either be [`Column`]s or
+    /// (Name, Type) Tuples
+    pub fn new<N, C, I>(name: N, columns: C) -> Self
     where
         N: Into<String>,
-        C: Into<Vec<(String, Types)>>,
+        C: IntoIterator<Item = I>,
+        I: Into<Column>,
     {
         Self {
             name: name.into(),
-            columns: columns.into(),
+            columns: columns.into_iter().map(|c| c.into()).collect(),
+            constraints: Vec::new(),
+            strict: false,
+            without_rowid: false,
         }
     }
 
+    /// Adds a new Constraint to the Table
+    pub fn constraint(mut self, constraint: Constraint) -> Self {
+        self.constraints.push(constraint);
+        self
+    }
+
+    /// Enforces the Types of the Columns for every Value, this is only supported by SQLite, as
+    /// other Dialects always do this
+    pub fn strict(mut self) -> Self {
+        self.strict = true;
+        self
+    }
+
+    /// Stores the Rows by their Primary Key instead of an implicit Row-ID, this is only supported
+    /// by SQLite
+    pub fn without_rowid(mut self) -> Self {
+        self.without_rowid = true;
+        self
+    }
+
     /// Gets a Set of the Columns of the Table
     pub fn columns(&self) -> HashSet<String> {
-        self.columns.iter().map(|(n, _)| n.to_string()).collect()
+        self.columns.iter().map(|c| c.name.clone()).collect()
+    }
+
+    /// The Name of the Table
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The Definitions of the Columns in Order
+    pub fn column_definitions(&self) -> &[Column] {
+        &self.columns
+    }
+
+    /// The Constraints of the Table
+    pub fn constraints(&self) -> &[Constraint] {
+        &self.constraints
+    }
+
+    /// Whether or not the Table is strict
+    pub fn is_strict(&self) -> bool {
+        self.strict
+    }
+
+    /// Whether or not the Table has no Row-ID
+    pub fn is_without_rowid(&self) -> bool {
+        self.without_rowid
     }
 }
 
 impl Statement for CreateTable {
-    fn format<F>(&self, _: &mut F) -> crate::sql::Sql
+    fn format<F>(&self, fmt: &mut F) -> Result<crate::sql::Sql, crate::fmt::FormatError>
     where
         F: crate::fmt::Formatter,
     {
-        dbg!(&self.name);
-        todo!()
+        fmt.create_table()
+            .name(&self.name)
+            .columns(&self.columns)
+            .constraints(&self.constraints)
+            .strict(self.strict)
+            .without_rowid(self.without_rowid)
+            .finish()
     }
 }
@@ -0,0 +1,99 @@
+use std::collections::HashSet;
+
+use crate::{
+    fmt::{CreateViewBuilder, FmtBuilder, FormatError},
+    sql::Sql,
+    verify::{RootTableDefinitions, VerifyError, VerifyTable},
+    Statement,
+};
+
+/// Creates a new View, which stores the Query under the given Name, so that it can be used like
+/// any other Table
+pub struct CreateView<S>
+where
+    S: Statement,
+{
+    name: String,
+    columns: Vec<String>,
+    query: S,
+}
+
+impl<S> CreateView<S>
+where
+    S: Statement,
+{
+    /// Creates a new View of the Query with the given Name
+    pub fn new<N>(name: N, query: S) -> Self
+    where
+        N: Into<String>,
+    {
+        Self {
+            name: name.into(),
+            columns: Vec::new(),
+            query,
+        }
+    }
+
+    /// Explicitly names the Columns returned by the View
+    pub fn columns<I, N>(mut self, columns: I) -> Self
+    where
+        I: IntoIterator<Item = N>,
+        N: Into<String>,
+    {
+        self.columns = columns.into_iter().map(|c| c.into()).collect();
+        self
+    }
+
+    /// The Name of the View
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl<S> CreateView<S>
+where
+    S: Statement + VerifyTable,
+{
+    /// Verifies the Query of the View and then adds the View to the Definitions, so that it can
+    /// be used by the following Queries
+    pub fn register(&self, roots: &mut RootTableDefinitions) -> Result<(), VerifyError> {
+        let mut columns = self.query.get_columns(roots)?;
+
+        if !self.columns.is_empty() {
+            if self.columns.len() != columns.len() {
+                return Err(VerifyError::ColumnCountMismatch {
+                    expected: self.columns.len(),
+                    found: columns.len(),
+                });
+            }
+            columns = self.columns.clone();
+        }
+
+        let mut fields = HashSet::with_capacity(columns.len());
+        for column in columns {
+            if fields.contains(&column) {
+                return Err(VerifyError::DuplicateColumn { column });
+            }
+            fields.insert(column);
+        }
+        roots.add_table(self.name.clone(), fields);
+
+        Ok(())
+    }
+}
+
+impl<S> Statement for CreateView<S>
+where
+    S: Statement,
+{
+    fn format<F>(&self, fmt: &mut F) -> Result<Sql, FormatError>
+    where
+        F: crate::fmt::Formatter,
+    {
+        fmt.create_view()
+            .name(&self.name)
+            .columns(&self.columns)
+            .query(&self.query)
+            .finish()
+    }
+}
@@ -0,0 +1,96 @@
+/// The Units used for truncating Timestamps and for Intervals
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DateTimeUnit {
+    /// Years
+    Year,
+    /// Months
+    Month,
+    /// Days
+    Day,
+    /// Hours
+    Hour,
+    /// Minutes
+    Minute,
+    /// Seconds
+    Second,
+}
+
+/// The Parts that can be extracted from a Timestamp as Numbers
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DatePart {
+    /// The Year
+    Year,
+    /// The Month, starting at 1 for January
+    Month,
+    /// The Day of the Month, starting at 1
+    Day,
+    /// The Hour of the Day, from 0 to 23
+    Hour,
+    /// The Minute of the Hour, from 0 to 59
+    Minute,
+    /// The Second of the Minute, from 0 to 59
+    Second,
+    /// The Day of the Week, starting at 0 for Sunday
+    DayOfWeek,
+    /// The Day of the Year, starting at 1
+    DayOfYear,
+}
+
+/// A Duration in a single Unit, which can be added to or subtracted from Timestamps
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Interval {
+    amount: i64,
+    unit: DateTimeUnit,
+}
+
+impl Interval {
+    /// Creates a new Interval of the Amount of Units, which may also be negative
+    pub fn new(amount: i64, unit: DateTimeUnit) -> Self {
+        Self { amount, unit }
+    }
+
+    /// The given Number of Years
+    pub fn years(amount: i64) -> Self {
+        Self::new(amount, DateTimeUnit::Year)
+    }
+
+    /// The given Number of Months
+    pub fn months(amount: i64) -> Self {
+        Self::new(amount, DateTimeUnit::Month)
+    }
+
+    /// The given Number of Days
+    pub fn days(amount: i64) -> Self {
+        Self::new(amount, DateTimeUnit::Day)
+    }
+
+    /// The given Number of Hours
+    pub fn hours(amount: i64) -> Self {
+        Self::new(amount, DateTimeUnit::Hour)
+    }
+
+    /// The given Number of Minutes
+    pub fn minutes(amount: i64) -> Self {
+        Self::new(amount, DateTimeUnit::Minute)
+    }
+
+    /// The given Number of Seconds
+    pub fn seconds(amount: i64) -> Self {
+        Self::new(amount, DateTimeUnit::Second)
+    }
+
+    /// The Amount of Units
+    pub fn amount(&self) -> i64 {
+        self.amount
+    }
+
+    /// The Unit of the Interval
+    pub fn unit(&self) -> DateTimeUnit {
+        self.unit
+    }
+
+    /// The Interval with the opposite Direction, or None if the Amount can't be negated
+    pub fn negated(self) -> Option<Self> {
+        Some(Self::new(self.amount.checked_neg()?, self.unit))
+    }
+}
@@ -1,11 +1,92 @@
-use crate::{Condition, Table};
+use std::collections::HashSet;
+
+use crate::{
+    fmt::{DeleteBuilder, FmtBuilder, FormatError},
+    sql::Sql,
+    verify::{
+        self, RootTableDefinitions, Scope, VerifyCondition, VerifyError, VerifyReturning,
+        VerifyTable,
+    },
+    Condition, Fields, Statement, Table,
+};
 
 /// A Delete Statement
-pub struct Delete<T, C>
+pub struct Delete<T, C, R = ()>
 where
     T: Table,
     C: Condition,
+    R: Fields,
 {
     table: T,
     condition: C,
+    returning: R,
+}
+
+impl<T, C> Delete<T, C>
+where
+    T: Table,
+    C: Condition,
+{
+    /// Creates a new Delete of the Rows in the Table matching the Condition
+    pub fn new(table: T, condition: C) -> Self {
+        Self {
+            table,
+            condition,
+            returning: (),
+        }
+    }
+}
+
+impl<T, C, R> Delete<T, C, R>
+where
+    T: Table,
+    C: Condition,
+    R: Fields,
+{
+    /// Returns the given Fields of the deleted Rows
+    pub fn returning<R2>(self, fields: R2) -> Delete<T, C, R2>
+    where
+        R2: Fields,
+    {
+        Delete {
+            table: self.table,
+            condition: self.condition,
+            returning: fields,
+        }
+    }
+}
+
+impl<T, C, R> Statement for Delete<T, C, R>
+where
+    T: Table,
+    C: Condition,
+    R: Fields,
+{
+    fn format<F>(&self, fmt: &mut F) -> Result<Sql, FormatError>
+    where
+        F: crate::fmt::Formatter,
+    {
+        fmt.delete()
+            .table(&self.table)
+            .condition(&self.condition)
+            .returning(&self.returning)
+            .finish()
+    }
+}
+
+impl<T, C, R> VerifyReturning for Delete<T, C, R>
+where
+    T: VerifyTable,
+    C: VerifyCondition,
+    R: Fields,
+{
+    fn get_returned_fields(
+        &self,
+        roots: &RootTableDefinitions,
+    ) -> Result<HashSet<String>, VerifyError> {
+        let fields = self.table.get_fields(roots)?;
+        self.condition.verify(&Scope::new(&fields), roots)?;
+
+        verify::returned_fields(&self.returning, &fields)
+    }
 }
@@ -0,0 +1,90 @@
+use crate::{
+    fmt::{DropBuilder, FmtBuilder, FormatError},
+    sql::Sql,
+    Statement,
+};
+
+/// The Kinds of Objects that can be dropped
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DropKind {
+    /// `DROP TABLE`
+    Table,
+    /// `DROP INDEX`
+    Index,
+    /// `DROP VIEW`
+    View,
+}
+
+/// Removes a Table, Index or View from the Database
+pub struct DropStatement {
+    kind: DropKind,
+    name: String,
+    if_exists: bool,
+    cascade: bool,
+}
+
+impl DropStatement {
+    /// Creates a new Statement that drops the Object of the given Kind
+    pub fn new<N>(kind: DropKind, name: N) -> Self
+    where
+        N: Into<String>,
+    {
+        Self {
+            kind,
+            name: name.into(),
+            if_exists: false,
+            cascade: false,
+        }
+    }
+
+    /// Drops the Table with the given Name
+    pub fn table<N>(name: N) -> Self
+    where
+        N: Into<String>,
+    {
+        Self::new(DropKind::Table, name)
+    }
+
+    /// Drops the Index with the given Name
+    pub fn index<N>(name: N) -> Self
+    where
+        N: Into<String>,
+    {
+        Self::new(DropKind::Index, name)
+    }
+
+    /// Drops the View with the given Name
+    pub fn view<N>(name: N) -> Self
+    where
+        N: Into<String>,
+    {
+        Self::new(DropKind::View, name)
+    }
+
+    /// Does not fail if the Object does not exist
+    pub fn if_exists(mut self) -> Self {
+        self.if_exists = true;
+        self
+    }
+
+    /// Also drops all the Objects that depend on this one, which is only supported by some
+    /// Dialects, like PostgreSQL
+    pub fn cascade(mut self) -> Self {
+        self.cascade = true;
+        self
+    }
+}
+
+impl Statement for DropStatement {
+    fn format<F>(&self, fmt: &mut F) -> Result<Sql, FormatError>
+    where
+        F: crate::fmt::Formatter,
+    {
+        fmt.drop()
+            .kind(self.kind)
+            .name(&self.name)
+            .if_exists(self.if_exists)
+            .cascade(self.cascade)
+            .finish()
+    }
+}
@@ -0,0 +1,994 @@
+use crate::{
+    fmt::{self, ConditionBuilder, ExpressionBuilder, FieldsBuilder, FmtBuilder, FormatError},
+    sql::{Sql, Types},
+    verify::{RootTableDefinitions, Scope, VerifyCondition, VerifyError},
+    Aggregate, Collation, Comparison, Condition, DatePart, DateTimeUnit, Fields, Interval,
+    JsonPath,
+};
+
+/// The Operators that combine two Values into a new one
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BinaryOperator {
+    /// `+`
+    Add,
+    /// `-`
+    Subtract,
+    /// `*`
+    Multiply,
+    /// `/`
+    Divide,
+    /// `%`
+    Modulo,
+    /// Concatenates two Strings, either using `||` or `CONCAT` depending on the Dialect
+    Concat,
+}
+
+/// An Expression that computes a single Value, which can be used as a Field, as a Condition, as
+/// a Key for ordering or as the new Value of a Column.
+///
+/// Plain Strings are converted into Column-References, Literals have to be created explicitly
+/// using [`Expression::value`] or [`Expression::text`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum Expression {
+    /// A Reference to a Column
+    Column(String),
+    /// A raw Literal, like a Number or `NULL`
+    Literal(String),
+    /// A String-Literal, which will be quoted by the Formatter
+    Text(String),
+    /// A Placeholder for a bound Parameter
+    Parameter,
+    /// The Column of the Row, that could not be inserted due to a Conflict, see
+    /// [`OnConflict`](crate::OnConflict)
+    Excluded(String),
+    /// Combines the two Values using the Operator
+    Binary {
+        /// The left Operand
+        left: Box<Expression>,
+        /// The Operator
+        operator: BinaryOperator,
+        /// The right Operand
+        right: Box<Expression>,
+    },
+    /// Compares the two Values
+    Compare {
+        /// The left Operand
+        left: Box<Expression>,
+        /// The Comparison
+        comparison: Comparison,
+        /// The right Operand
+        right: Box<Expression>,
+    },
+    /// Checks if the Value is NULL, `IS NULL` or `IS NOT NULL`
+    IsNull {
+        /// The Value to check
+        operand: Box<Expression>,
+        /// Whether or not the Check is negated
+        negated: bool,
+    },
+    /// A CASE Expression, see [`Case`]
+    Case(Case),
+    /// Converts the Value into the given Type
+    Cast {
+        /// The Value to convert
+        operand: Box<Expression>,
+        /// The Type to convert it to
+        target: Types,
+    },
+    /// The first of the Values that is not NULL
+    Coalesce(Vec<Expression>),
+    /// NULL if both Values are equal, otherwise the first Value
+    NullIf(Box<Expression>, Box<Expression>),
+    /// Extracts the Value at the Path from a JSON Document
+    JsonExtract {
+        /// The JSON Document
+        operand: Box<Expression>,
+        /// The Path of the Value inside of the Document
+        path: JsonPath,
+        /// Whether the Value is returned as a plain SQL Value instead of JSON
+        as_text: bool,
+    },
+    /// Builds a JSON Object from the (Key, Value) Pairs
+    JsonObject(Vec<(String, Expression)>),
+    /// Builds a JSON Array from the Values
+    JsonArray(Vec<Expression>),
+    /// The current Date and Time
+    Now,
+    /// Truncates the Timestamp to the Start of the Unit it is in
+    DateTrunc {
+        /// The Timestamp to truncate
+        operand: Box<Expression>,
+        /// The Unit to truncate to
+        unit: DateTimeUnit,
+    },
+    /// Adds the Interval to the Timestamp
+    DateAdd {
+        /// The Timestamp
+        operand: Box<Expression>,
+        /// The Interval to add
+        interval: Interval,
+        /// Whether or not the Interval is subtracted instead
+        subtract: bool,
+    },
+    /// Extracts a Part of the Timestamp as a Number
+    DatePart {
+        /// The Timestamp
+        operand: Box<Expression>,
+        /// The Part to extract
+        part: DatePart,
+    },
+    /// Formats the Timestamp as Text using a `strftime` Format, like `%Y-%m-%d`
+    DateFormat {
+        /// The Timestamp
+        operand: Box<Expression>,
+        /// The Format
+        format: String,
+    },
+    /// Uses the Collation for comparing or sorting the String
+    Collate {
+        /// The String
+        operand: Box<Expression>,
+        /// The Collation to use
+        collation: Collation,
+    },
+    /// A Call to an Aggregate-Function, which can only be used once the Rows are grouped, like in
+    /// the HAVING Clause
+    Aggregate(Box<Aggregate>),
+}
+
+impl Expression {
+    /// Creates a new Reference to the Column
+    pub fn column<N>(name: N) -> Self
+    where
+        N: Into<String>,
+    {
+        Self::Column(name.into())
+    }
+
+    /// Creates a new raw Literal, the Value is used as is and is therefore intended for Numbers
+    pub fn value<V>(value: V) -> Self
+    where
+        V: std::fmt::Display,
+    {
+        Self::Literal(value.to_string())
+    }
+
+    /// Creates a new String-Literal
+    pub fn text<V>(value: V) -> Self
+    where
+        V: Into<String>,
+    {
+        Self::Text(value.into())
+    }
+
+    /// Refers to the Column of the Row, that could not be inserted due to a Conflict
+    pub fn excluded<N>(column: N) -> Self
+    where
+        N: Into<String>,
+    {
+        Self::Excluded(column.into())
+    }
+
+    /// A Placeholder for a bound Parameter, like `?` or `$1` depending on the Dialect
+    pub fn parameter() -> Self {
+        Self::Parameter
+    }
+
+    /// `NULL`
+    pub fn null() -> Self {
+        Self::Literal("NULL".to_string())
+    }
+
+    /// The first of the Values that is not NULL
+    pub fn coalesce<I, E>(values: I) -> Self
+    where
+        I: IntoIterator<Item = E>,
+        E: Into<Expression>,
+    {
+        Self::Coalesce(values.into_iter().map(|v| v.into()).collect())
+    }
+
+    /// NULL if both Values are equal, otherwise the first Value
+    pub fn null_if<L, R>(left: L, right: R) -> Self
+    where
+        L: Into<Expression>,
+        R: Into<Expression>,
+    {
+        Self::NullIf(Box::new(left.into()), Box::new(right.into()))
+    }
+
+    /// Builds a JSON Object from the (Key, Value) Pairs
+    pub fn json_object<I, K, V>(entries: I) -> Self
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: Into<String>,
+        V: Into<Expression>,
+    {
+        Self::JsonObject(
+            entries
+                .into_iter()
+                .map(|(k, v)| (k.into(), v.into()))
+                .collect(),
+        )
+    }
+
+    /// Builds a JSON Array from the Values
+    pub fn json_array<I, V>(values: I) -> Self
+    where
+        I: IntoIterator<Item = V>,
+        V: Into<Expression>,
+    {
+        Self::JsonArray(values.into_iter().map(|v| v.into()).collect())
+    }
+
+    /// The current Date and Time
+    pub fn now() -> Self {
+        Self::Now
+    }
+
+    /// Combines the current Value with the other one using the Operator
+    pub fn binary<R>(self, operator: BinaryOperator, right: R) -> Self
+    where
+        R: Into<Expression>,
+    {
+        Self::Binary {
+            left: Box::new(self),
+            operator,
+            right: Box::new(right.into()),
+        }
+    }
+
+    /// `self + right`
+    pub fn plus<R>(self, right: R) -> Self
+    where
+        R: Into<Expression>,
+    {
+        self.binary(BinaryOperator::Add, right)
+    }
+
+    /// `self - right`
+    pub fn minus<R>(self, right: R) -> Self
+    where
+        R: Into<Expression>,
+    {
+        self.binary(BinaryOperator::Subtract, right)
+    }
+
+    /// `self * right`
+    pub fn multiply<R>(self, right: R) -> Self
+    where
+        R: Into<Expression>,
+    {
+        self.binary(BinaryOperator::Multiply, right)
+    }
+
+    /// `self / right`
+    pub fn divide<R>(self, right: R) -> Self
+    where
+        R: Into<Expression>,
+    {
+        self.binary(BinaryOperator::Divide, right)
+    }
+
+    /// `self % right`
+    pub fn modulo<R>(self, right: R) -> Self
+    where
+        R: Into<Expression>,
+    {
+        self.binary(BinaryOperator::Modulo, right)
+    }
+
+    /// Concatenates the current String with the other one
+    pub fn concat<R>(self, right: R) -> Self
+    where
+        R: Into<Expression>,
+    {
+        self.binary(BinaryOperator::Concat, right)
+    }
+
+    /// Compares the current Value with the other one
+    pub fn compare<R>(self, comparison: Comparison, right: R) -> Self
+    where
+        R: Into<Expression>,
+    {
+        Self::Compare {
+            left: Box::new(self),
+            comparison,
+            right: Box::new(right.into()),
+        }
+    }
+
+    /// `self = right`
+    pub fn equals<R>(self, right: R) -> Self
+    where
+        R: Into<Expression>,
+    {
+        self.compare(Comparison::Equal, right)
+    }
+
+    /// `self IS NULL`
+    pub fn is_null(self) -> Self {
+        Self::IsNull {
+            operand: Box::new(self),
+            negated: false,
+        }
+    }
+
+    /// `self IS NOT NULL`
+    pub fn is_not_null(self) -> Self {
+        Self::IsNull {
+            operand: Box::new(self),
+            negated: true,
+        }
+    }
+
+    /// Converts the current Value into the given Type, `CAST(self AS type)`
+    pub fn cast(self, target: Types) -> Self {
+        Self::Cast {
+            operand: Box::new(self),
+            target,
+        }
+    }
+
+    /// Extracts the Value at the Path from the current JSON Document as JSON, like `self->'$.key'`
+    pub fn json<P>(self, path: P) -> Self
+    where
+        P: Into<JsonPath>,
+    {
+        Self::JsonExtract {
+            operand: Box::new(self),
+            path: path.into(),
+            as_text: false,
+        }
+    }
+
+    /// Extracts the Value at the Path from the current JSON Document as a plain SQL Value, like
+    /// `self->>'$.key'`, which can then be compared to other Values
+    pub fn json_text<P>(self, path: P) -> Self
+    where
+        P: Into<JsonPath>,
+    {
+        Self::JsonExtract {
+            operand: Box::new(self),
+            path: path.into(),
+            as_text: true,
+        }
+    }
+
+    /// Truncates the current Timestamp to the Start of the Unit it is in, like the Start of its
+    /// Month
+    pub fn date_trunc(self, unit: DateTimeUnit) -> Self {
+        Self::DateTrunc {
+            operand: Box::new(self),
+            unit,
+        }
+    }
+
+    /// Adds the Interval to the current Timestamp
+    pub fn date_add(self, interval: Interval) -> Self {
+        Self::DateAdd {
+            operand: Box::new(self),
+            interval,
+            subtract: false,
+        }
+    }
+
+    /// Subtracts the Interval from the current Timestamp
+    pub fn date_sub(self, interval: Interval) -> Self {
+        Self::DateAdd {
+            operand: Box::new(self),
+            interval,
+            subtract: true,
+        }
+    }
+
+    /// Extracts the Part of the current Timestamp as a Number
+    pub fn date_part(self, part: DatePart) -> Self {
+        Self::DatePart {
+            operand: Box::new(self),
+            part,
+        }
+    }
+
+    /// Formats the current Timestamp as Text using a `strftime` Format, like `%Y-%m-%d`, which
+    /// is translated by the Formatters of Dialects with other Formats
+    pub fn date_format<S>(self, format: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self::DateFormat {
+            operand: Box::new(self),
+            format: format.into(),
+        }
+    }
+
+    /// Compares or sorts the current String using the Collation, like `self COLLATE NOCASE`
+    pub fn collate(self, collation: Collation) -> Self {
+        Self::Collate {
+            operand: Box::new(self),
+            collation,
+        }
+    }
+
+    /// Names the resulting Field, when the Expression is selected
+    pub fn alias<N>(self, alias: N) -> NamedExpression
+    where
+        N: Into<String>,
+    {
+        NamedExpression {
+            expression: self,
+            alias: alias.into(),
+        }
+    }
+
+    /// The Columns referenced by the Expression
+    pub fn columns(&self) -> Vec<String> {
+        let mut result = Vec::new();
+        self.collect_columns(&mut result);
+        result
+    }
+
+    /// The Columns referenced by the Expression outside of any Aggregate-Function
+    pub fn ungrouped_columns(&self) -> Vec<String> {
+        let mut result = Vec::new();
+        self.walk(&mut |expression| match expression {
+            Self::Column(name) => {
+                result.push(name.clone());
+                true
+            }
+            Self::Aggregate(_) => false,
+            _ => true,
+        });
+        result
+    }
+
+    /// Whether or not the Expression contains a Call to an Aggregate-Function
+    pub fn contains_aggregate(&self) -> bool {
+        let mut found = false;
+        self.walk(&mut |expression| {
+            found |= matches!(expression, Self::Aggregate(_));
+            !found
+        });
+        found
+    }
+
+    fn collect_columns(&self, result: &mut Vec<String>) {
+        self.walk(&mut |expression| {
+            if let Self::Column(name) = expression {
+                result.push(name.clone());
+            }
+            true
+        });
+    }
+
+    /// Visits the Expression and then all of its Operands, as long as the Visitor returns true
+    fn walk(&self, visitor: &mut dyn FnMut(&Expression) -> bool) {
+        if !visitor(self) {
+            return;
+        }
+
+        match self {
+            Self::Column(_)
+            | Self::Literal(_)
+            | Self::Text(_)
+            | Self::Parameter
+            | Self::Excluded(_)
+            | Self::Now => {}
+            Self::Binary { left, right, .. } | Self::Compare { left, right, .. } => {
+                left.walk(visitor);
+                right.walk(visitor);
+            }
+            Self::IsNull { operand, .. }
+            | Self::Cast { operand, .. }
+            | Self::JsonExtract { operand, .. }
+            | Self::DateTrunc { operand, .. }
+            | Self::DateAdd { operand, .. }
+            | Self::DatePart { operand, .. }
+            | Self::DateFormat { operand, .. }
+            | Self::Collate { operand, .. } => operand.walk(visitor),
+            Self::Case(case) => {
+                let operands = case.operand.iter().map(|o| o.as_ref());
+                let branches = case.branches.iter().flat_map(|(w, t)| [w, t]);
+                let otherwise = case.otherwise.iter().map(|o| o.as_ref());
+                for part in operands.chain(branches).chain(otherwise) {
+                    part.walk(visitor);
+                }
+            }
+            Self::Coalesce(values) | Self::JsonArray(values) => {
+                for value in values {
+                    value.walk(visitor);
+                }
+            }
+            Self::NullIf(left, right) => {
+                left.walk(visitor);
+                right.walk(visitor);
+            }
+            Self::JsonObject(entries) => {
+                for (_, value) in entries {
+                    value.walk(visitor);
+                }
+            }
+            Self::Aggregate(aggregate) => {
+                if let Some(argument) = aggregate.argument() {
+                    argument.walk(visitor);
+                }
+            }
+        }
+    }
+
+    /// Replaces all the References to the Column with References to its new Name
+    pub(crate) fn rename_column(&mut self, from: &str, to: &str) {
+        match self {
+            Self::Column(name) => {
+                if name == from {
+                    *name = to.to_string();
+                }
+            }
+            Self::Literal(_) | Self::Text(_) | Self::Parameter | Self::Excluded(_) | Self::Now => {}
+            Self::Binary { left, right, .. } | Self::Compare { left, right, .. } => {
+                left.rename_column(from, to);
+                right.rename_column(from, to);
+            }
+            Self::IsNull { operand, .. }
+            | Self::Cast { operand, .. }
+            | Self::JsonExtract { operand, .. }
+            | Self::DateTrunc { operand, .. }
+            | Self::DateAdd { operand, .. }
+            | Self::DatePart { operand, .. }
+            | Self::DateFormat { operand, .. }
+            | Self::Collate { operand, .. } => operand.rename_column(from, to),
+            Self::Case(case) => {
+                let operands = case.operand.iter_mut().map(|o| o.as_mut());
+                let branches = case.branches.iter_mut().flat_map(|(w, t)| [w, t]);
+                let otherwise = case.otherwise.iter_mut().map(|o| o.as_mut());
+                for part in operands.chain(branches).chain(otherwise) {
+                    part.rename_column(from, to);
+                }
+            }
+            Self::Coalesce(values) | Self::JsonArray(values) => {
+                for value in values {
+                    value.rename_column(from, to);
+                }
+            }
+            Self::NullIf(left, right) => {
+                left.rename_column(from, to);
+                right.rename_column(from, to);
+            }
+            Self::JsonObject(entries) => {
+                for (_, value) in entries {
+                    value.rename_column(from, to);
+                }
+            }
+            Self::Aggregate(aggregate) => aggregate.rename_column(from, to),
+        }
+    }
+
+    /// The Number of bound Parameters used by the Expression
+    pub fn parameters(&self) -> usize {
+        match self {
+            Self::Parameter => 1,
+            Self::Column(_) | Self::Literal(_) | Self::Text(_) | Self::Excluded(_) | Self::Now => 0,
+            Self::Binary { left, right, .. } | Self::Compare { left, right, .. } => {
+                left.parameters() + right.parameters()
+            }
+            Self::NullIf(left, right) => left.parameters() + right.parameters(),
+            Self::IsNull { operand, .. }
+            | Self::Cast { operand, .. }
+            | Self::JsonExtract { operand, .. }
+            | Self::DateTrunc { operand, .. }
+            | Self::DateAdd { operand, .. }
+            | Self::DatePart { operand, .. }
+            | Self::DateFormat { operand, .. }
+            | Self::Collate { operand, .. } => operand.parameters(),
+            Self::Case(case) => {
+                let operands = case.operand.iter().map(|o| o.as_ref());
+                let branches = case.branches.iter().flat_map(|(w, t)| [w, t]);
+                let otherwise = case.otherwise.iter().map(|o| o.as_ref());
+                operands
+                    .chain(branches)
+                    .chain(otherwise)
+                    .map(|p| p.parameters())
+                    .sum()
+            }
+            Self::Coalesce(values) | Self::JsonArray(values) => {
+                values.iter().map(|v| v.parameters()).sum()
+            }
+            Self::JsonObject(entries) => entries.iter().map(|(_, v)| v.parameters()).sum(),
+            Self::Aggregate(aggregate) => aggregate.argument().map_or(0, |a| a.parameters()),
+        }
+    }
+
+    /// The Name of the resulting Field, when the Expression is selected without an Alias
+    pub fn name(&self) -> String {
+        match self {
+            Self::Column(name) | Self::Literal(name) => name.clone(),
+            Self::Text(value) => format!("'{}'", value),
+            Self::Parameter => "?".to_string(),
+            Self::Excluded(column) => format!("excluded.{}", column),
+            Self::Binary {
+                left,
+                operator,
+                right,
+            } => {
+                let op_str = match operator {
+                    BinaryOperator::Add => "+",
+                    BinaryOperator::Subtract => "-",
+                    BinaryOperator::Multiply => "*",
+                    BinaryOperator::Divide => "/",
+                    BinaryOperator::Modulo => "%",
+                    BinaryOperator::Concat => "||",
+                };
+                format!("{} {} {}", left.name(), op_str, right.name())
+            }
+            Self::Compare {
+                left,
+                comparison,
+                right,
+            } => {
+                let op_str = match comparison {
+                    Comparison::Equal => "=",
+                    Comparison::NotEqual => "<>",
+                    Comparison::Less => "<",
+                    Comparison::LessEqual => "<=",
+                    Comparison::Greater => ">",
+                    Comparison::GreaterEqual => ">=",
+                };
+                format!("{} {} {}", left.name(), op_str, right.name())
+            }
+            Self::IsNull { operand, negated } => match negated {
+                true => format!("{} is not null", operand.name()),
+                false => format!("{} is null", operand.name()),
+            },
+            Self::Case(_) => "case".to_string(),
+            Self::Cast { operand, .. } => format!("cast({})", operand.name()),
+            Self::Coalesce(values) => {
+                let values_str = values
+                    .iter()
+                    .map(|v| v.name())
+                    .intersperse(", ".to_string())
+                    .collect::<String>();
+                format!("coalesce({})", values_str)
+            }
+            Self::NullIf(left, right) => format!("nullif({}, {})", left.name(), right.name()),
+            Self::JsonExtract {
+                operand,
+                path,
+                as_text,
+            } => match as_text {
+                true => format!("{}->>'{}'", operand.name(), path),
+                false => format!("{}->'{}'", operand.name(), path),
+            },
+            Self::JsonObject(_) => "json_object".to_string(),
+            Self::JsonArray(_) => "json_array".to_string(),
+            Self::Now => "now".to_string(),
+            Self::DateTrunc { operand, .. } => format!("date_trunc({})", operand.name()),
+            Self::DateAdd { operand, .. } => format!("date_add({})", operand.name()),
+            Self::DatePart { operand, .. } => format!("date_part({})", operand.name()),
+            Self::DateFormat { operand, .. } => format!("date_format({})", operand.name()),
+            Self::Collate { operand, .. } => operand.name(),
+            Self::Aggregate(aggregate) => aggregate.name(),
+        }
+    }
+
+    /// Formats the Expression using the provided Formatter
+    pub fn format<F>(&self, fmt: &mut F) -> Result<Sql, FormatError>
+    where
+        F: fmt::Formatter,
+    {
+        let result = match self {
+            Self::Column(name) | Self::Literal(name) => Sql::new(name.as_str()),
+            Self::Text(value) => fmt.expression().text(value),
+            Self::Parameter => fmt.expression().parameter(),
+            Self::Excluded(column) => fmt.expression().excluded(column),
+            Self::Binary {
+                left,
+                operator,
+                right,
+            } => {
+                let left = left.format_operand(fmt)?;
+                let right = right.format_operand(fmt)?;
+                fmt.expression().binary(left, *operator, right)
+            }
+            Self::Compare {
+                left,
+                comparison,
+                right,
+            } => {
+                let left = left.format_operand(fmt)?;
+                let right = right.format_operand(fmt)?;
+                fmt.condition().compare(left, *comparison, right)
+            }
+            Self::IsNull { operand, negated } => {
+                let operand = operand.format_operand(fmt)?;
+                fmt.condition().null_check(operand, *negated)
+            }
+            Self::Case(case) => {
+                let operand = match case.operand.as_ref() {
+                    Some(operand) => Some(operand.format(fmt)?),
+                    None => None,
+                };
+                let mut branches = Vec::with_capacity(case.branches.len());
+                for (condition, result) in case.branches.iter() {
+                    branches.push((condition.format(fmt)?, result.format(fmt)?));
+                }
+                let otherwise = match case.otherwise.as_ref() {
+                    Some(otherwise) => Some(otherwise.format(fmt)?),
+                    None => None,
+                };
+                fmt.expression().case(operand, branches, otherwise)
+            }
+            Self::Cast { operand, target } => {
+                let operand = operand.format(fmt)?;
+                fmt.expression().cast(operand, target)
+            }
+            Self::Coalesce(values) => {
+                let values = values
+                    .iter()
+                    .map(|v| v.format(fmt))
+                    .collect::<Result<Vec<_>, _>>()?;
+                fmt.expression().coalesce(values)
+            }
+            Self::NullIf(left, right) => {
+                let left = left.format(fmt)?;
+                let right = right.format(fmt)?;
+                fmt.expression().null_if(left, right)
+            }
+            Self::JsonExtract {
+                operand,
+                path,
+                as_text,
+            } => {
+                let operand = operand.format_operand(fmt)?;
+                fmt.expression().json_extract(operand, path, *as_text)
+            }
+            Self::JsonObject(entries) => {
+                let entries = entries
+                    .iter()
+                    .map(|(k, v)| Ok((k.clone(), v.format(fmt)?)))
+                    .collect::<Result<Vec<_>, FormatError>>()?;
+                fmt.expression().json_object(entries)
+            }
+            Self::JsonArray(values) => {
+                let values = values
+                    .iter()
+                    .map(|v| v.format(fmt))
+                    .collect::<Result<Vec<_>, _>>()?;
+                fmt.expression().json_array(values)
+            }
+            Self::Now => fmt.expression().now(),
+            Self::DateTrunc { operand, unit } => {
+                let operand = operand.format(fmt)?;
+                fmt.expression().date_trunc(operand, *unit)?
+            }
+            Self::DateAdd {
+                operand,
+                interval,
+                subtract,
+            } => {
+                let operand = operand.format(fmt)?;
+                let interval = match subtract {
+                    true => interval.negated().ok_or_else(|| FormatError::Invalid {
+                        reason: format!("The Interval {} can't be subtracted", interval.amount()),
+                    })?,
+                    false => *interval,
+                };
+                fmt.expression().date_add(operand, &interval)?
+            }
+            Self::DatePart { operand, part } => {
+                let operand = operand.format(fmt)?;
+                fmt.expression().date_part(operand, *part)?
+            }
+            Self::DateFormat { operand, format } => {
+                let operand = operand.format(fmt)?;
+                fmt.expression().date_format(operand, format)?
+            }
+            Self::Collate { operand, collation } => {
+                let operand = operand.format_operand(fmt)?;
+                fmt.expression().collate(operand, collation)?
+            }
+            Self::Aggregate(aggregate) => aggregate.format_call(fmt)?,
+        };
+        Ok(result)
+    }
+
+    /// Formats the Expression as the Operand of an Operator, which requires Parentheses around
+    /// other Operations to keep their Precedence
+    fn format_operand<F>(&self, fmt: &mut F) -> Result<Sql, FormatError>
+    where
+        F: fmt::Formatter,
+    {
+        let inner = self.format(fmt)?;
+        match self {
+            Self::Binary { .. }
+            | Self::Compare { .. }
+            | Self::IsNull { .. }
+            | Self::JsonExtract { .. } => Ok(Sql::new(format!("({})", inner))),
+            // Otherwise `x - -1` would be displayed as `x--1`, which starts a Comment
+            Self::Literal(value) if value.starts_with('-') => Ok(Sql::new(format!("({})", inner))),
+            _ => Ok(inner),
+        }
+    }
+}
+
+impl From<Aggregate> for Expression {
+    fn from(aggregate: Aggregate) -> Self {
+        Self::Aggregate(Box::new(aggregate))
+    }
+}
+impl From<&str> for Expression {
+    fn from(name: &str) -> Self {
+        Self::Column(name.to_string())
+    }
+}
+impl From<String> for Expression {
+    fn from(name: String) -> Self {
+        Self::Column(name)
+    }
+}
+impl From<i64> for Expression {
+    fn from(value: i64) -> Self {
+        Self::value(value)
+    }
+}
+impl From<f64> for Expression {
+    fn from(value: f64) -> Self {
+        Self::value(value)
+    }
+}
+impl From<Case> for Expression {
+    fn from(case: Case) -> Self {
+        Self::Case(case)
+    }
+}
+
+/// A CASE Expression, which either checks a list of Conditions (`CASE WHEN ...`) or compares a
+/// single Value against a list of Values (`CASE value WHEN ...`)
+#[derive(Debug, Clone, PartialEq)]
+pub struct Case {
+    operand: Option<Box<Expression>>,
+    branches: Vec<(Expression, Expression)>,
+    otherwise: Option<Box<Expression>>,
+}
+
+impl Case {
+    /// Creates a new CASE Expression, where every Branch has its own Condition
+    pub fn new() -> Self {
+        Self {
+            operand: None,
+            branches: Vec::new(),
+            otherwise: None,
+        }
+    }
+
+    /// Creates a new CASE Expression, where the Operand is compared against the Value of every
+    /// Branch
+    pub fn simple<O>(operand: O) -> Self
+    where
+        O: Into<Expression>,
+    {
+        Self {
+            operand: Some(Box::new(operand.into())),
+            branches: Vec::new(),
+            otherwise: None,
+        }
+    }
+
+    /// Adds a new Branch, which results in the given Value once the Condition matches
+    pub fn when<C, R>(mut self, condition: C, result: R) -> Self
+    where
+        C: Into<Expression>,
+        R: Into<Expression>,
+    {
+        self.branches.push((condition.into(), result.into()));
+        self
+    }
+
+    /// Sets the Value for when none of the Branches match, defaults to NULL
+    pub fn otherwise<R>(mut self, result: R) -> Self
+    where
+        R: Into<Expression>,
+    {
+        self.otherwise = Some(Box::new(result.into()));
+        self
+    }
+
+    /// Names the resulting Field, when the Expression is selected
+    pub fn alias<N>(self, alias: N) -> NamedExpression
+    where
+        N: Into<String>,
+    {
+        Expression::from(self).alias(alias)
+    }
+}
+
+impl Default for Case {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Fields for Expression {
+    type FieldIter = std::iter::Once<String>;
+
+    fn to_iterator(&self) -> Self::FieldIter {
+        std::iter::once(self.name())
+    }
+
+    fn columns(&self) -> Vec<String> {
+        Expression::columns(self)
+    }
+
+    fn ungrouped_columns(&self) -> Vec<String> {
+        Expression::ungrouped_columns(self)
+    }
+
+    fn contains_aggregate(&self) -> bool {
+        Expression::contains_aggregate(self)
+    }
+
+    fn format<F>(&self, fmt: &mut F) -> Result<Sql, FormatError>
+    where
+        F: fmt::Formatter,
+    {
+        let expression = Expression::format(self, fmt)?;
+
+        let mut fields = fmt.fields();
+        fields.add_field(expression.into());
+        fields.finish()
+    }
+}
+
+impl Condition for Expression {
+    fn format<F>(&self, fmt: &mut F) -> Result<Option<Sql>, FormatError>
+    where
+        F: fmt::Formatter,
+    {
+        Expression::format(self, fmt).map(Some)
+    }
+}
+impl VerifyCondition for Expression {
+    fn verify(&self, scope: &Scope<'_>, _: &RootTableDefinitions) -> Result<(), VerifyError> {
+        for column in self.columns() {
+            scope.resolve(&column)?;
+        }
+        Ok(())
+    }
+}
+
+/// An Expression with an explicit Name, when being selected
+#[derive(Debug, Clone, PartialEq)]
+pub struct NamedExpression {
+    expression: Expression,
+    alias: String,
+}
+
+impl Fields for NamedExpression {
+    type FieldIter = std::iter::Once<String>;
+
+    fn to_iterator(&self) -> Self::FieldIter {
+        std::iter::once(self.alias.clone())
+    }
+
+    fn columns(&self) -> Vec<String> {
+        self.expression.columns()
+    }
+
+    fn ungrouped_columns(&self) -> Vec<String> {
+        self.expression.ungrouped_columns()
+    }
+
+    fn contains_aggregate(&self) -> bool {
+        self.expression.contains_aggregate()
+    }
+
+    fn format<F>(&self, fmt: &mut F) -> Result<Sql, FormatError>
+    where
+        F: fmt::Formatter,
+    {
+        let expression = self.expression.format(fmt)?;
+
+        let mut fields = fmt.fields();
+        fields.add_field(format!("{} AS {}", expression, self.alias));
+        fields.finish()
+    }
+}
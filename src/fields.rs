@@ -1,5 +1,5 @@
 use crate::{
-    fmt::{self, FieldsBuilder, FmtBuilder},
+    fmt::{self, FieldsBuilder, FmtBuilder, FormatError},
     sql::Sql,
     Fields,
 };
@@ -11,11 +11,13 @@ impl Fields for &str {
         std::iter::once(self.to_string())
     }
 
-    fn format<F>(&self, _: &mut F) -> Sql
+    fn format<F>(&self, fmt: &mut F) -> Result<Sql, FormatError>
     where
         F: fmt::Formatter,
     {
-        todo!()
+        let mut fields = fmt.fields();
+        fields.add_field(self.to_string());
+        fields.finish()
     }
 }
 impl Fields for String {
@@ -25,11 +27,13 @@ impl Fields for String {
         std::iter::once(self.clone())
     }
 
-    fn format<F>(&self, _: &mut F) -> Sql
+    fn format<F>(&self, fmt: &mut F) -> Result<Sql, FormatError>
     where
         F: fmt::Formatter,
     {
-        todo!()
+        let mut fields = fmt.fields();
+        fields.add_field(self.to_string());
+        fields.finish()
     }
 }
 
@@ -40,7 +44,7 @@ impl Fields for Vec<String> {
         self.clone().into_iter()
     }
 
-    fn format<F>(&self, fmt: &mut F) -> Sql
+    fn format<F>(&self, fmt: &mut F) -> Result<Sql, FormatError>
     where
         F: fmt::Formatter,
     {
@@ -64,11 +68,17 @@ impl Fields for &[&str] {
             .into_iter()
     }
 
-    fn format<F>(&self, _: &mut F) -> Sql
+    fn format<F>(&self, fmt: &mut F) -> Result<Sql, FormatError>
     where
         F: fmt::Formatter,
     {
-        todo!()
+        let mut fields = fmt.fields();
+
+        for field in self.iter() {
+            fields.add_field(field.to_string());
+        }
+
+        fields.finish()
     }
 }
 impl Fields for &[String] {
@@ -81,11 +91,17 @@ impl Fields for &[String] {
             .into_iter()
     }
 
-    fn format<F>(&self, _: &mut F) -> Sql
+    fn format<F>(&self, fmt: &mut F) -> Result<Sql, FormatError>
     where
         F: fmt::Formatter,
     {
-        todo!()
+        let mut fields = fmt.fields();
+
+        for field in self.iter() {
+            fields.add_field(field.to_string());
+        }
+
+        fields.finish()
     }
 }
 
@@ -99,7 +115,7 @@ impl<const N: usize> Fields for &[&str; N] {
             .into_iter()
     }
 
-    fn format<F>(&self, fmt: &mut F) -> Sql
+    fn format<F>(&self, fmt: &mut F) -> Result<Sql, FormatError>
     where
         F: fmt::Formatter,
     {
@@ -113,6 +129,22 @@ impl<const N: usize> Fields for &[&str; N] {
     }
 }
 
+/// Represents no Fields at all, which is used for Statements that don't return any Rows
+impl Fields for () {
+    type FieldIter = std::iter::Empty<String>;
+
+    fn to_iterator(&self) -> Self::FieldIter {
+        std::iter::empty()
+    }
+
+    fn format<F>(&self, fmt: &mut F) -> Result<Sql, FormatError>
+    where
+        F: fmt::Formatter,
+    {
+        fmt.fields().finish()
+    }
+}
+
 /// Represents all Fields available on the underlying Table
 pub struct AllFields {}
 impl Fields for AllFields {
@@ -122,10 +154,81 @@ impl Fields for AllFields {
         std::iter::empty()
     }
 
-    fn format<F>(&self, _: &mut F) -> Sql
+    fn selects_all(&self) -> bool {
+        true
+    }
+
+    fn format<F>(&self, fmt: &mut F) -> Result<Sql, FormatError>
     where
         F: fmt::Formatter,
     {
-        todo!()
+        let mut fields = fmt.fields();
+        fields.add_field("*".to_string());
+        fields.finish()
     }
 }
+
+macro_rules! tuple_fields {
+    ($($name:ident),+) => {
+        #[allow(non_snake_case)]
+        impl<$($name),+> Fields for ($($name,)+)
+        where
+            $($name: Fields),+
+        {
+            type FieldIter = std::vec::IntoIter<String>;
+
+            fn to_iterator(&self) -> Self::FieldIter {
+                let ($($name,)+) = self;
+
+                let mut result = Vec::new();
+                $(result.extend($name.to_iterator());)+
+                result.into_iter()
+            }
+
+            fn columns(&self) -> Vec<String> {
+                let ($($name,)+) = self;
+
+                let mut result = Vec::new();
+                $(result.extend($name.columns());)+
+                result
+            }
+
+            fn ungrouped_columns(&self) -> Vec<String> {
+                let ($($name,)+) = self;
+
+                let mut result = Vec::new();
+                $(result.extend($name.ungrouped_columns());)+
+                result
+            }
+
+            fn contains_aggregate(&self) -> bool {
+                let ($($name,)+) = self;
+
+                false $(|| $name.contains_aggregate())+
+            }
+
+            fn selects_all(&self) -> bool {
+                let ($($name,)+) = self;
+
+                false $(|| $name.selects_all())+
+            }
+
+            fn format<F>(&self, fmt: &mut F) -> Result<Sql, FormatError>
+            where
+                F: fmt::Formatter,
+            {
+                let ($($name,)+) = self;
+
+                let mut fields = fmt.fields();
+                $(fields.add_field($name.format(fmt)?.into());)+
+                fields.finish()
+            }
+        }
+    };
+}
+
+tuple_fields!(F1, F2);
+tuple_fields!(F1, F2, F3);
+tuple_fields!(F1, F2, F3, F4);
+tuple_fields!(F1, F2, F3, F4, F5);
+tuple_fields!(F1, F2, F3, F4, F5, F6);
@@ -1,13 +1,56 @@
 //! TODO
 
-use crate::{sql::Sql, Condition, Fields, Table};
+use crate::{
+    sql::{Sql, Types},
+    AggregateFunction, AlterOperation, BinaryOperator, Collation, Column, Comparison, Condition,
+    Constraint, CreateTable, DatePart, DateTimeUnit, Distinct, DropKind, Expression, Fields,
+    FtsFunction, Interval, IsolationLevel, JoinKind, JsonPath, Locking, OnConflict, OrderBy,
+    SetOperator, Statement, Table, TransactionMode, Window, WindowFunction,
+};
 
 pub mod sqlite;
 
+/// The Error that could be returned when attempting to format a Query
+#[derive(Debug, Clone, PartialEq)]
+pub enum FormatError {
+    /// The Query uses a Feature that is not supported by the targeted SQL Flavor
+    Unsupported {
+        /// A Description of the Feature
+        feature: String,
+    },
+    /// A single Part of the Query already exceeds the Limits of the Formatter
+    LimitExceeded {
+        /// A Description of the exceeded Limit
+        limit: String,
+    },
+    /// The Statement contradicts the Definitions it is based on, like dropping a Constraint that
+    /// does not exist
+    Invalid {
+        /// A Description of the Problem
+        reason: String,
+    },
+    /// A Row does not contain a Value for every Column
+    RowLength {
+        /// The Number of Columns
+        expected: usize,
+        /// The Number of Values in the Row
+        found: usize,
+    },
+}
+
+/// The Limits of a single Statement, that are enforced by the targeted Database
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Limits {
+    /// The maximum Number of bound Parameters
+    pub parameters: usize,
+    /// The maximum Length of the SQL in Bytes
+    pub length: usize,
+}
+
 /// An underlying Trait for the Formatting
 pub trait FmtBuilder {
     /// Converts the current Builder into the final SQL String
-    fn finish(&mut self) -> Sql;
+    fn finish(&mut self) -> Result<Sql, FormatError>;
 }
 
 /// The Trait describing the Target Formatters
@@ -18,6 +61,36 @@ pub trait Formatter {
     type FieldsBuilder: FieldsBuilder;
     /// The Builder for Conditionals in a Query
     type ConditionBuilder: ConditionBuilder;
+    /// The Builder for Joins between Tables
+    type JoinBuilder: JoinBuilder;
+    /// The Builder for Expressions
+    type ExpressionBuilder: ExpressionBuilder;
+    /// The Builder for Compounds of Selects
+    type CompoundBuilder: CompoundBuilder;
+    /// The Builder for WITH Clauses
+    type WithBuilder: WithBuilder;
+    /// The Builder for Insert Statements
+    type InsertBuilder: InsertBuilder;
+    /// The Builder for Update Statements
+    type UpdateBuilder: UpdateBuilder;
+    /// The Builder for Delete Statements
+    type DeleteBuilder: DeleteBuilder;
+    /// The Builder for Drop Statements
+    type DropBuilder: DropBuilder;
+    /// The Builder for Create Table Statements
+    type CreateTableBuilder: CreateTableBuilder;
+    /// The Builder for Alter Table Statements
+    type AlterTableBuilder: AlterTableBuilder;
+    /// The Builder for Create Index Statements
+    type CreateIndexBuilder: CreateIndexBuilder;
+    /// The Builder for Create View Statements
+    type CreateViewBuilder: CreateViewBuilder;
+    /// The Builder for Statements controlling Transactions
+    type TransactionBuilder: TransactionBuilder;
+    /// The Builder for Scripts of multiple Statements
+    type ScriptBuilder: ScriptBuilder;
+    /// The Builder for Create Statements of Full-Text Search Tables
+    type CreateFtsTableBuilder: CreateFtsTableBuilder;
 
     /// Obtains a Select Builder
     fn select(&mut self) -> Self::SelectBuilder;
@@ -25,6 +98,39 @@ pub trait Formatter {
     fn fields(&mut self) -> Self::FieldsBuilder;
     /// Obtains a Conditional Builder
     fn condition(&mut self) -> Self::ConditionBuilder;
+    /// Obtains a Join Builder
+    fn join(&mut self) -> Self::JoinBuilder;
+    /// Obtains an Expression Builder
+    fn expression(&mut self) -> Self::ExpressionBuilder;
+    /// Obtains a Compound Builder
+    fn compound(&mut self) -> Self::CompoundBuilder;
+    /// Obtains a With Builder
+    fn with(&mut self) -> Self::WithBuilder;
+    /// The Limits of a single Statement for the targeted Database
+    fn limits(&self) -> Limits;
+
+    /// Obtains an Insert Builder
+    fn insert(&mut self) -> Self::InsertBuilder;
+    /// Obtains an Update Builder
+    fn update(&mut self) -> Self::UpdateBuilder;
+    /// Obtains a Delete Builder
+    fn delete(&mut self) -> Self::DeleteBuilder;
+    /// Obtains a Drop Builder
+    fn drop(&mut self) -> Self::DropBuilder;
+    /// Obtains a Create Table Builder
+    fn create_table(&mut self) -> Self::CreateTableBuilder;
+    /// Obtains an Alter Table Builder
+    fn alter_table(&mut self) -> Self::AlterTableBuilder;
+    /// Obtains a Create Index Builder
+    fn create_index(&mut self) -> Self::CreateIndexBuilder;
+    /// Obtains a Create View Builder
+    fn create_view(&mut self) -> Self::CreateViewBuilder;
+    /// Obtains a Transaction Builder
+    fn transaction(&mut self) -> Self::TransactionBuilder;
+    /// Obtains a Script Builder
+    fn script(&mut self) -> Self::ScriptBuilder;
+    /// Obtains a Create Full-Text Search Table Builder
+    fn create_fts_table(&mut self) -> Self::CreateFtsTableBuilder;
 }
 
 /// The Builder Trait for Select Queries
@@ -41,11 +147,398 @@ pub trait SelectBuilder: FmtBuilder {
         's: 'o,
         F: Fields;
 
+    /// How duplicate Rows should be removed, None keeps all Rows
+    fn distinct<'s, 'o>(&'s mut self, distinct: Option<&Distinct>) -> &'o mut Self
+    where
+        's: 'o;
+
     /// The Condition for the Select
     fn condition<'s, 'o, C>(&'s mut self, condition: &C) -> &'o mut Self
     where
         's: 'o,
         C: Condition;
+
+    /// The Columns used for grouping the Rows
+    fn group_by<'s, 'o>(&'s mut self, columns: &[String]) -> &'o mut Self
+    where
+        's: 'o;
+
+    /// The Condition applied to the grouped Rows
+    fn having<'s, 'o, C>(&'s mut self, condition: &C) -> &'o mut Self
+    where
+        's: 'o,
+        C: Condition;
+
+    /// The named Windows, that can be referred to by Window-Functions
+    fn windows<'s, 'o>(&'s mut self, windows: &[(String, Window)]) -> &'o mut Self
+    where
+        's: 'o;
+
+    /// The Keys used for ordering the resulting Rows
+    fn order_by<'s, 'o>(&'s mut self, order: &[OrderBy]) -> &'o mut Self
+    where
+        's: 'o;
+
+    /// The maximum Number of Rows returned, depending on the Dialect this is expressed using
+    /// LIMIT, TOP or FETCH FIRST
+    fn limit<'s, 'o>(&'s mut self, limit: Option<u64>) -> &'o mut Self
+    where
+        's: 'o;
+
+    /// The Number of Rows to skip before returning any
+    fn offset<'s, 'o>(&'s mut self, offset: Option<u64>) -> &'o mut Self
+    where
+        's: 'o;
+
+    /// The Locks acquired on the selected Rows, None to not lock them
+    fn locking<'s, 'o>(&'s mut self, locking: Option<&Locking>) -> &'o mut Self
+    where
+        's: 'o;
+}
+
+/// The Builder Trait for Joins
+pub trait JoinBuilder: FmtBuilder {
+    /// The Kind of Join
+    fn kind<'s, 'o>(&'s mut self, kind: JoinKind) -> &'o mut Self
+    where
+        's: 'o;
+
+    /// The left Table of the Join
+    fn left<'s, 'o, T>(&'s mut self, table: &T) -> &'o mut Self
+    where
+        's: 'o,
+        T: Table;
+
+    /// The right Table of the Join
+    fn right<'s, 'o, T>(&'s mut self, table: &T) -> &'o mut Self
+    where
+        's: 'o,
+        T: Table;
+
+    /// The Condition used for the ON-Clause
+    fn condition<'s, 'o, C>(&'s mut self, condition: &C) -> &'o mut Self
+    where
+        's: 'o,
+        C: Condition;
+}
+
+/// The Builder Trait for Compounds of Selects, like UNIONs
+pub trait CompoundBuilder: FmtBuilder {
+    /// The left Side of the Compound
+    fn left<'s, 'o, S>(&'s mut self, statement: &S) -> &'o mut Self
+    where
+        's: 'o,
+        S: Statement;
+
+    /// The Operator combining both Sides
+    fn operator<'s, 'o>(&'s mut self, operator: SetOperator) -> &'o mut Self
+    where
+        's: 'o;
+
+    /// The right Side of the Compound
+    fn right<'s, 'o, S>(&'s mut self, statement: &S) -> &'o mut Self
+    where
+        's: 'o,
+        S: Statement;
+}
+
+/// The Builder Trait for Statements with a WITH Clause
+pub trait WithBuilder: FmtBuilder {
+    /// Whether or not the Expressions can refer to themselves
+    fn recursive<'s, 'o>(&'s mut self, recursive: bool) -> &'o mut Self
+    where
+        's: 'o;
+
+    /// Adds a new named Expression, the Columns can be empty to use the ones returned by the Query
+    fn cte<'s, 'o, S>(&'s mut self, name: &str, columns: &[String], query: &S) -> &'o mut Self
+    where
+        's: 'o,
+        S: Statement;
+
+    /// The Statement that uses the Expressions
+    fn statement<'s, 'o, S>(&'s mut self, statement: &S) -> &'o mut Self
+    where
+        's: 'o,
+        S: Statement;
+}
+
+/// The Builder Trait for Insert Statements
+pub trait InsertBuilder: FmtBuilder {
+    /// The Table the Row is inserted into
+    fn table<'s, 'o, T>(&'s mut self, table: &T) -> &'o mut Self
+    where
+        's: 'o,
+        T: Table;
+
+    /// The Values of the Columns of the new Row, empty to use the Defaults of all Columns
+    fn values<'s, 'o>(&'s mut self, values: &[(String, Expression)]) -> &'o mut Self
+    where
+        's: 'o;
+
+    /// Multiple Rows of already formatted Values, which replace the Values of a single Row
+    fn rows<'s, 'o>(&'s mut self, columns: &[String], rows: Vec<Vec<Sql>>) -> &'o mut Self
+    where
+        's: 'o;
+
+    /// The Rows of the Table are inserted instead of a single Row of Values, where the Columns of
+    /// the Table are assigned to the given Columns in Order. Empty Columns assign all the Columns
+    /// of the target Table in Order
+    fn query<'s, 'o, T>(&'s mut self, columns: &[String], query: &T) -> &'o mut Self
+    where
+        's: 'o,
+        T: Table;
+
+    /// How Conflicts with existing Rows should be handled, None fails the Statement
+    fn on_conflict<'s, 'o>(&'s mut self, conflict: Option<&OnConflict>) -> &'o mut Self
+    where
+        's: 'o;
+
+    /// The Fields of the inserted Row that should be returned, depending on the Dialect this is
+    /// expressed using RETURNING or OUTPUT. Empty Fields don't return anything
+    fn returning<'s, 'o, F>(&'s mut self, fields: &F) -> &'o mut Self
+    where
+        's: 'o,
+        F: Fields;
+}
+
+/// The Builder Trait for Update Statements
+pub trait UpdateBuilder: FmtBuilder {
+    /// The Table whose Rows are updated
+    fn table<'s, 'o, T>(&'s mut self, table: &T) -> &'o mut Self
+    where
+        's: 'o,
+        T: Table;
+
+    /// The Assignments of the SET Clause
+    fn assignments<'s, 'o>(&'s mut self, assignments: &[(String, Expression)]) -> &'o mut Self
+    where
+        's: 'o;
+
+    /// The Condition selecting the Rows to update
+    fn condition<'s, 'o, C>(&'s mut self, condition: &C) -> &'o mut Self
+    where
+        's: 'o,
+        C: Condition;
+
+    /// The Fields of the updated Rows that should be returned, depending on the Dialect this is
+    /// expressed using RETURNING or OUTPUT. Empty Fields don't return anything
+    fn returning<'s, 'o, F>(&'s mut self, fields: &F) -> &'o mut Self
+    where
+        's: 'o,
+        F: Fields;
+}
+
+/// The Builder Trait for Delete Statements
+pub trait DeleteBuilder: FmtBuilder {
+    /// The Table whose Rows are deleted
+    fn table<'s, 'o, T>(&'s mut self, table: &T) -> &'o mut Self
+    where
+        's: 'o,
+        T: Table;
+
+    /// The Condition selecting the Rows to delete
+    fn condition<'s, 'o, C>(&'s mut self, condition: &C) -> &'o mut Self
+    where
+        's: 'o,
+        C: Condition;
+
+    /// The Fields of the deleted Rows that should be returned, depending on the Dialect this is
+    /// expressed using RETURNING or OUTPUT. Empty Fields don't return anything
+    fn returning<'s, 'o, F>(&'s mut self, fields: &F) -> &'o mut Self
+    where
+        's: 'o,
+        F: Fields;
+}
+
+/// The Builder Trait for Drop Statements
+pub trait DropBuilder: FmtBuilder {
+    /// The Kind of Object being dropped
+    fn kind<'s, 'o>(&'s mut self, kind: DropKind) -> &'o mut Self
+    where
+        's: 'o;
+
+    /// The Name of the Object
+    fn name<'s, 'o>(&'s mut self, name: &str) -> &'o mut Self
+    where
+        's: 'o;
+
+    /// Whether or not a missing Object should be ignored
+    fn if_exists<'s, 'o>(&'s mut self, if_exists: bool) -> &'o mut Self
+    where
+        's: 'o;
+
+    /// Whether or not the dependent Objects should be dropped as well
+    fn cascade<'s, 'o>(&'s mut self, cascade: bool) -> &'o mut Self
+    where
+        's: 'o;
+}
+
+/// The Builder Trait for Create Table Statements
+pub trait CreateTableBuilder: FmtBuilder {
+    /// The Name of the new Table
+    fn name<'s, 'o>(&'s mut self, name: &str) -> &'o mut Self
+    where
+        's: 'o;
+
+    /// The Columns of the new Table
+    fn columns<'s, 'o>(&'s mut self, columns: &[Column]) -> &'o mut Self
+    where
+        's: 'o;
+
+    /// The Constraints of the new Table
+    fn constraints<'s, 'o>(&'s mut self, constraints: &[Constraint]) -> &'o mut Self
+    where
+        's: 'o;
+
+    /// Whether or not the Types of the Columns are enforced for every Value. Dialects that always
+    /// enforce them should ignore this
+    fn strict<'s, 'o>(&'s mut self, strict: bool) -> &'o mut Self
+    where
+        's: 'o;
+
+    /// Whether or not the Rows are stored by their Primary Key instead of an implicit Row-ID.
+    /// Dialects without Row-IDs should ignore this
+    fn without_rowid<'s, 'o>(&'s mut self, without_rowid: bool) -> &'o mut Self
+    where
+        's: 'o;
+}
+
+/// The Builder Trait for Alter Table Statements, which may result in multiple Statements
+/// depending on the Dialect
+pub trait AlterTableBuilder: FmtBuilder {
+    /// The Name of the altered Table
+    fn table<'s, 'o>(&'s mut self, table: &str) -> &'o mut Self
+    where
+        's: 'o;
+
+    /// The Operations to perform in Order
+    fn operations<'s, 'o>(&'s mut self, operations: &[AlterOperation]) -> &'o mut Self
+    where
+        's: 'o;
+
+    /// The current Definition of the Table, if known
+    fn definition<'s, 'o>(&'s mut self, definition: Option<&CreateTable>) -> &'o mut Self
+    where
+        's: 'o;
+
+    /// Whether or not Foreign Keys are enforced on the Connection
+    fn foreign_keys<'s, 'o>(&'s mut self, enabled: bool) -> &'o mut Self
+    where
+        's: 'o;
+
+    /// The already formatted Statements, that recreate the Indexes and Triggers if the Table has
+    /// to be rebuilt
+    fn restore<'s, 'o>(&'s mut self, statements: Sql) -> &'o mut Self
+    where
+        's: 'o;
+}
+
+/// The Builder Trait for Create Index Statements
+pub trait CreateIndexBuilder: FmtBuilder {
+    /// The Name of the new Index
+    fn name<'s, 'o>(&'s mut self, name: &str) -> &'o mut Self
+    where
+        's: 'o;
+
+    /// The indexed Table
+    fn table<'s, 'o>(&'s mut self, table: &str) -> &'o mut Self
+    where
+        's: 'o;
+
+    /// Whether or not the Keys should be unique
+    fn unique<'s, 'o>(&'s mut self, unique: bool) -> &'o mut Self
+    where
+        's: 'o;
+
+    /// The Keys of the Index in Order
+    fn keys<'s, 'o>(&'s mut self, keys: &[OrderBy]) -> &'o mut Self
+    where
+        's: 'o;
+
+    /// The Condition for the Rows included in a partial Index
+    fn condition<'s, 'o, C>(&'s mut self, condition: &C) -> &'o mut Self
+    where
+        's: 'o,
+        C: Condition;
+}
+
+/// The Builder Trait for Create View Statements
+pub trait CreateViewBuilder: FmtBuilder {
+    /// The Name of the new View
+    fn name<'s, 'o>(&'s mut self, name: &str) -> &'o mut Self
+    where
+        's: 'o;
+
+    /// The Names of the Columns, which can be empty to use the ones returned by the Query
+    fn columns<'s, 'o>(&'s mut self, columns: &[String]) -> &'o mut Self
+    where
+        's: 'o;
+
+    /// The Query of the View
+    fn query<'s, 'o, S>(&'s mut self, query: &S) -> &'o mut Self
+    where
+        's: 'o,
+        S: Statement;
+}
+
+/// The Builder Trait for Statements controlling Transactions, only one of the Statements should
+/// be configured
+pub trait TransactionBuilder: FmtBuilder {
+    /// Starts a new Transaction
+    fn begin<'s, 'o>(
+        &'s mut self,
+        mode: Option<TransactionMode>,
+        isolation: Option<IsolationLevel>,
+    ) -> &'o mut Self
+    where
+        's: 'o;
+
+    /// Commits the current Transaction
+    fn commit<'s, 'o>(&'s mut self) -> &'o mut Self
+    where
+        's: 'o;
+
+    /// Reverts the current Transaction or, if given, all the Changes since the Savepoint
+    fn rollback<'s, 'o>(&'s mut self, savepoint: Option<&str>) -> &'o mut Self
+    where
+        's: 'o;
+
+    /// Creates a new Savepoint
+    fn savepoint<'s, 'o>(&'s mut self, name: &str) -> &'o mut Self
+    where
+        's: 'o;
+
+    /// Releases the Savepoint
+    fn release<'s, 'o>(&'s mut self, name: &str) -> &'o mut Self
+    where
+        's: 'o;
+}
+
+/// The Builder Trait for Scripts of multiple Statements
+pub trait ScriptBuilder: FmtBuilder {
+    /// Appends the Statement to the Script
+    fn statement<'s, 'o, S>(&'s mut self, statement: &S) -> &'o mut Self
+    where
+        's: 'o,
+        S: Statement;
+}
+
+/// The Builder Trait for Create Statements of Full-Text Search Tables
+pub trait CreateFtsTableBuilder: FmtBuilder {
+    /// The Name of the new Table
+    fn name<'s, 'o>(&'s mut self, name: &str) -> &'o mut Self
+    where
+        's: 'o;
+
+    /// The (Name, indexed) Tuples of the Columns of the new Table
+    fn columns<'s, 'o>(&'s mut self, columns: &[(String, bool)]) -> &'o mut Self
+    where
+        's: 'o;
+
+    /// The (Key, Value) Tuples of the Options of the Table, which are specific to the Dialect
+    fn options<'s, 'o>(&'s mut self, options: &[(String, String)]) -> &'o mut Self
+    where
+        's: 'o;
 }
 
 /// The Builder Trait for Fields
@@ -59,9 +552,109 @@ pub trait ConditionBuilder: FmtBuilder {
     /// Generates the SQL for an Equals comparison between the Two elements
     fn equal(self, left: Sql, right: Sql) -> Sql;
 
+    /// Generates the SQL for comparing the Two elements using the given Operator
+    fn compare(self, left: Sql, comparison: Comparison, right: Sql) -> Sql;
+
+    /// Generates the SQL for checking if the left element is contained in the Results of the
+    /// Sub-Query
+    fn in_select(self, left: Sql, query: Sql, negated: bool) -> Sql;
+
+    /// Generates the SQL for checking if the Sub-Query returns any Rows
+    fn exists(self, query: Sql, negated: bool) -> Sql;
+
+    /// Generates the SQL for checking if the Value is NULL
+    fn null_check(self, operand: Sql, negated: bool) -> Sql;
+
     /// Combines the two sides with a logical AND
     fn and(self, left: Sql, right: Sql) -> Sql;
 
     /// Combines the two sides with a logical OR
     fn or(self, left: Sql, right: Sql) -> Sql;
+
+    /// Generates the SQL for checking if the Column, or the Table for all its Columns, matches the
+    /// Full-Text Query
+    fn full_text_match(self, target: Sql, query: Sql) -> Result<Sql, FormatError>;
+}
+
+/// The Builder Trait for Expressions
+pub trait ExpressionBuilder {
+    /// Generates the SQL for a Call to the Aggregate-Function, where an Argument of None refers to
+    /// all Rows (`*`)
+    fn aggregate(
+        self,
+        function: &AggregateFunction,
+        argument: Option<Sql>,
+        distinct: bool,
+    ) -> Result<Sql, FormatError>;
+
+    /// Generates the SQL for a Call to one of the Window-Functions, including Aggregates
+    fn window_function(self, function: &WindowFunction) -> Result<Sql, FormatError>;
+
+    /// Generates the SQL for applying the Call to a Function over the Window
+    fn over(self, function: Sql, window: &Window) -> Result<Sql, FormatError>;
+
+    /// Generates the SQL for a String-Literal with the given Value
+    fn text(self, value: &str) -> Sql;
+
+    /// Generates the SQL for referring to the Column of the Row, that could not be inserted due to
+    /// a Conflict
+    fn excluded(self, column: &str) -> Sql;
+
+    /// Generates the SQL for a Placeholder of a bound Parameter
+    fn parameter(self) -> Sql;
+
+    /// Generates the SQL for combining the two Values using the Operator
+    fn binary(self, left: Sql, operator: BinaryOperator, right: Sql) -> Sql;
+
+    /// Generates the SQL for a CASE Expression, the Operand is only set for the simple Form that
+    /// compares it against the Values of the Branches
+    fn case(self, operand: Option<Sql>, branches: Vec<(Sql, Sql)>, otherwise: Option<Sql>) -> Sql;
+
+    /// Generates the SQL for converting the Value into the given Type
+    fn cast(self, operand: Sql, target: &Types) -> Sql;
+
+    /// Generates the SQL for selecting the first Value that is not NULL
+    fn coalesce(self, values: Vec<Sql>) -> Sql;
+
+    /// Generates the SQL for returning NULL if both Values are equal
+    fn null_if(self, left: Sql, right: Sql) -> Sql;
+
+    /// Generates the SQL for a Call to an auxiliary Function of a Full-Text Search on the Table
+    fn full_text_function(self, table: &str, function: &FtsFunction) -> Result<Sql, FormatError>;
+
+    /// Generates the SQL for extracting the Value at the Path from the JSON Document, either as
+    /// JSON or as a plain SQL Value, like `->`/`->>` in SQLite and PostgreSQL or `JSON_EXTRACT`
+    /// and `JSON_UNQUOTE` in MySQL
+    fn json_extract(self, operand: Sql, path: &JsonPath, as_text: bool) -> Sql;
+
+    /// Generates the SQL for building a JSON Object from the (Key, Value) Pairs
+    fn json_object(self, entries: Vec<(String, Sql)>) -> Sql;
+
+    /// Generates the SQL for building a JSON Array from the Values
+    fn json_array(self, values: Vec<Sql>) -> Sql;
+
+    /// Generates the SQL for the Table of the Elements of the JSON Document, optionally of the
+    /// Value at the Path inside of it
+    fn json_each(self, document: Sql, path: Option<&JsonPath>) -> Result<Sql, FormatError>;
+
+    /// Generates the SQL for the current Date and Time
+    fn now(self) -> Sql;
+
+    /// Generates the SQL for truncating the Timestamp to the Start of the Unit, like
+    /// `date_trunc` in PostgreSQL
+    fn date_trunc(self, operand: Sql, unit: DateTimeUnit) -> Result<Sql, FormatError>;
+
+    /// Generates the SQL for adding the Interval to the Timestamp, like `+ INTERVAL` in PostgreSQL
+    /// or `DATE_ADD` in MySQL
+    fn date_add(self, operand: Sql, interval: &Interval) -> Result<Sql, FormatError>;
+
+    /// Generates the SQL for extracting the Part of the Timestamp as a Number, like `EXTRACT`
+    fn date_part(self, operand: Sql, part: DatePart) -> Result<Sql, FormatError>;
+
+    /// Generates the SQL for formatting the Timestamp using the `strftime` Format, which has to be
+    /// translated for Dialects with other Formats
+    fn date_format(self, operand: Sql, format: &str) -> Result<Sql, FormatError>;
+
+    /// Generates the SQL for comparing or sorting the String using the Collation
+    fn collate(self, operand: Sql, collation: &Collation) -> Result<Sql, FormatError>;
 }
@@ -1,20 +1,46 @@
 //! TODO
 
-use crate::{sql::Sql, Condition, Fields, Statement, Table};
+use crate::{
+    alter_table,
+    sql::{Sql, Types},
+    AggregateFunction, AlterOperation, BinaryOperator, Collation, Column, Comparison, Condition,
+    ConflictTarget, Constraint, ConstraintKind, CreateTable, DatePart, DateTimeUnit, Direction,
+    Distinct, DropKind, Expression, Fields, FrameBound, FrameUnits, FtsFunction, Generated,
+    Interval, IsolationLevel, JoinKind, JsonPath, LockStrength, LockWait, Locking, Nulls,
+    OnConflict, OrderBy, SetOperator, Statement, Table, TransactionMode, Window, WindowFunction,
+};
 
-use super::{ConditionBuilder, FieldsBuilder, FmtBuilder, Formatter, SelectBuilder};
+use super::{
+    AlterTableBuilder, CompoundBuilder, ConditionBuilder, CreateFtsTableBuilder,
+    CreateIndexBuilder, CreateTableBuilder, CreateViewBuilder, DeleteBuilder, DropBuilder,
+    ExpressionBuilder, FieldsBuilder, FmtBuilder, FormatError, Formatter, InsertBuilder,
+    JoinBuilder, Limits, ScriptBuilder, SelectBuilder, TransactionBuilder, UpdateBuilder,
+    WithBuilder,
+};
 
 /// An SQL-Formatter that targets SQLite
-pub struct SqliteFormatter {}
+pub struct SqliteFormatter {
+    limits: Limits,
+}
 
 impl SqliteFormatter {
-    /// Creates a new Instance of the Formatter
+    /// Creates a new Instance of the Formatter, using the Limits of a default SQLite Build before
+    /// Version 3.32, which only allows for 999 Parameters
     pub fn new() -> Self {
-        Self {}
+        Self::with_limits(Limits {
+            parameters: 999,
+            length: 1_000_000,
+        })
+    }
+
+    /// Creates a new Instance of the Formatter with the given Limits, newer SQLite Builds allow
+    /// for up to 32766 Parameters
+    pub fn with_limits(limits: Limits) -> Self {
+        Self { limits }
     }
 
-    /// Formats the given Select Statement
-    pub fn format<S>(&mut self, s: &S) -> Sql
+    /// Formats the given Statement
+    pub fn format<S>(&mut self, s: &S) -> Result<Sql, FormatError>
     where
         S: Statement,
     {
@@ -22,7 +48,9 @@ impl SqliteFormatter {
     }
 
     fn duplicate(&self) -> Self {
-        Self {}
+        Self {
+            limits: self.limits,
+        }
     }
 }
 
@@ -36,6 +64,21 @@ impl Formatter for SqliteFormatter {
     type SelectBuilder = SqliteSelectBuilder;
     type FieldsBuilder = SqliteFieldsBuilder;
     type ConditionBuilder = SqliteConditionBuilder;
+    type JoinBuilder = SqliteJoinBuilder;
+    type ExpressionBuilder = SqliteExpressionBuilder;
+    type CompoundBuilder = SqliteCompoundBuilder;
+    type WithBuilder = SqliteWithBuilder;
+    type InsertBuilder = SqliteInsertBuilder;
+    type UpdateBuilder = SqliteUpdateBuilder;
+    type DeleteBuilder = SqliteDeleteBuilder;
+    type DropBuilder = SqliteDropBuilder;
+    type CreateTableBuilder = SqliteCreateTableBuilder;
+    type AlterTableBuilder = SqliteAlterTableBuilder;
+    type CreateIndexBuilder = SqliteCreateIndexBuilder;
+    type CreateViewBuilder = SqliteCreateViewBuilder;
+    type TransactionBuilder = SqliteTransactionBuilder;
+    type ScriptBuilder = SqliteScriptBuilder;
+    type CreateFtsTableBuilder = SqliteCreateFtsTableBuilder;
 
     fn select(&mut self) -> Self::SelectBuilder {
         SqliteSelectBuilder::new(self)
@@ -46,13 +89,82 @@ impl Formatter for SqliteFormatter {
     fn condition(&mut self) -> Self::ConditionBuilder {
         SqliteConditionBuilder::new()
     }
+    fn join(&mut self) -> Self::JoinBuilder {
+        SqliteJoinBuilder::new(self)
+    }
+    fn expression(&mut self) -> Self::ExpressionBuilder {
+        SqliteExpressionBuilder::new(self)
+    }
+    fn compound(&mut self) -> Self::CompoundBuilder {
+        SqliteCompoundBuilder::new(self)
+    }
+    fn with(&mut self) -> Self::WithBuilder {
+        SqliteWithBuilder::new(self)
+    }
+    fn limits(&self) -> Limits {
+        self.limits
+    }
+
+    fn insert(&mut self) -> Self::InsertBuilder {
+        SqliteInsertBuilder::new(self)
+    }
+    fn update(&mut self) -> Self::UpdateBuilder {
+        SqliteUpdateBuilder::new(self)
+    }
+    fn delete(&mut self) -> Self::DeleteBuilder {
+        SqliteDeleteBuilder::new(self)
+    }
+    fn drop(&mut self) -> Self::DropBuilder {
+        SqliteDropBuilder::new()
+    }
+    fn create_table(&mut self) -> Self::CreateTableBuilder {
+        SqliteCreateTableBuilder::new(self)
+    }
+    fn alter_table(&mut self) -> Self::AlterTableBuilder {
+        SqliteAlterTableBuilder::new(self)
+    }
+    fn create_index(&mut self) -> Self::CreateIndexBuilder {
+        SqliteCreateIndexBuilder::new(self)
+    }
+    fn create_view(&mut self) -> Self::CreateViewBuilder {
+        SqliteCreateViewBuilder::new(self)
+    }
+    fn transaction(&mut self) -> Self::TransactionBuilder {
+        SqliteTransactionBuilder::new()
+    }
+    fn script(&mut self) -> Self::ScriptBuilder {
+        SqliteScriptBuilder::new(self)
+    }
+    fn create_fts_table(&mut self) -> Self::CreateFtsTableBuilder {
+        SqliteCreateFtsTableBuilder::new()
+    }
+}
+
+/// Keeps track of the first Error encountered by a Builder, which will then be returned once the
+/// Builder is finished
+fn track<T>(error: &mut Option<FormatError>, result: Result<T, FormatError>) -> Option<T> {
+    match result {
+        Ok(value) => Some(value),
+        Err(e) => {
+            error.get_or_insert(e);
+            None
+        }
+    }
 }
 
 /// The Builder for Select Statements
 pub struct SqliteSelectBuilder {
     table: Option<Sql>,
     fields: Option<Sql>,
+    distinct: bool,
     condition: Option<Sql>,
+    group_by: Option<Sql>,
+    having: Option<Sql>,
+    windows: Option<Sql>,
+    order: Option<Sql>,
+    limit: Option<u64>,
+    offset: Option<u64>,
+    error: Option<FormatError>,
     root: SqliteFormatter,
 }
 
@@ -61,27 +173,71 @@ impl SqliteSelectBuilder {
         Self {
             table: None,
             fields: None,
+            distinct: false,
             condition: None,
+            group_by: None,
+            having: None,
+            windows: None,
+            order: None,
+            limit: None,
+            offset: None,
+            error: None,
             root: root.duplicate(),
         }
     }
 }
 
 impl FmtBuilder for SqliteSelectBuilder {
-    fn finish(&mut self) -> Sql {
+    fn finish(&mut self) -> Result<Sql, FormatError> {
+        if let Some(err) = self.error.take() {
+            return Err(err);
+        }
+
         let table_str = self.table.as_ref().expect("The Table should be set");
         let field_str = self.fields.as_ref().expect("The Fields should be set");
 
-        match self.condition.as_ref() {
-            Some(cond) if !cond.is_empty() => {
-                let raw_str = format!("SELECT {} FROM ({}) WHERE {}", field_str, table_str, cond);
-                Sql::new(raw_str)
+        let mut raw_str = if self.distinct {
+            format!("SELECT DISTINCT {} FROM {}", field_str, table_str)
+        } else {
+            format!("SELECT {} FROM {}", field_str, table_str)
+        };
+
+        if let Some(cond) = self.condition.as_ref().filter(|c| !c.is_empty()) {
+            raw_str.push_str(&format!(" WHERE {}", cond));
+        }
+
+        if let Some(group_by) = self.group_by.as_ref() {
+            raw_str.push_str(&format!(" GROUP BY {}", group_by));
+        }
+
+        if let Some(having) = self.having.as_ref().filter(|c| !c.is_empty()) {
+            raw_str.push_str(&format!(" HAVING {}", having));
+        }
+
+        if let Some(windows) = self.windows.as_ref() {
+            raw_str.push_str(&format!(" WINDOW {}", windows));
+        }
+
+        if let Some(order) = self.order.as_ref() {
+            raw_str.push_str(&format!(" ORDER BY {}", order));
+        }
+
+        // SQLite only allows an OFFSET as part of a LIMIT, where a negative Limit means that there
+        // is no upper bound
+        match (self.limit, self.offset) {
+            (Some(limit), Some(offset)) => {
+                raw_str.push_str(&format!(" LIMIT {} OFFSET {}", limit, offset));
             }
-            _ => {
-                let raw_str = format!("SELECT {} FROM ({})", field_str, table_str);
-                Sql::new(raw_str)
+            (Some(limit), None) => {
+                raw_str.push_str(&format!(" LIMIT {}", limit));
             }
-        }
+            (None, Some(offset)) => {
+                raw_str.push_str(&format!(" LIMIT -1 OFFSET {}", offset));
+            }
+            (None, None) => {}
+        };
+
+        Ok(Sql::new(raw_str))
     }
 }
 impl SelectBuilder for SqliteSelectBuilder {
@@ -90,7 +246,7 @@ impl SelectBuilder for SqliteSelectBuilder {
         's: 'o,
         T: Table,
     {
-        self.table = Some(table.format(&mut self.root));
+        self.table = track(&mut self.error, table.format(&mut self.root));
         self
     }
     fn fields<'s, 'o, F>(&'s mut self, fields: &F) -> &'o mut Self
@@ -98,7 +254,22 @@ impl SelectBuilder for SqliteSelectBuilder {
         's: 'o,
         F: Fields,
     {
-        self.fields = Some(fields.format(&mut self.root));
+        self.fields = track(&mut self.error, fields.format(&mut self.root));
+        self
+    }
+    fn distinct<'s, 'o>(&'s mut self, distinct: Option<&Distinct>) -> &'o mut Self
+    where
+        's: 'o,
+    {
+        match distinct {
+            None => self.distinct = false,
+            Some(Distinct::Rows) => self.distinct = true,
+            Some(Distinct::On(_)) => {
+                self.error.get_or_insert(FormatError::Unsupported {
+                    feature: "DISTINCT ON".to_string(),
+                });
+            }
+        };
         self
     }
     fn condition<'s, 'o, C>(&'s mut self, condition: &C) -> &'o mut Self
@@ -106,66 +277,1937 @@ impl SelectBuilder for SqliteSelectBuilder {
         's: 'o,
         C: Condition,
     {
-        self.condition = condition.format(&mut self.root);
+        self.condition = track(&mut self.error, condition.format(&mut self.root)).flatten();
+        self
+    }
+    fn group_by<'s, 'o>(&'s mut self, columns: &[String]) -> &'o mut Self
+    where
+        's: 'o,
+    {
+        if columns.is_empty() {
+            self.group_by = None;
+            return self;
+        }
+
+        let raw_str = columns
+            .iter()
+            .map(|c| c.as_str())
+            .intersperse(",")
+            .collect::<String>();
+
+        self.group_by = Some(Sql::new(raw_str));
+        self
+    }
+    fn having<'s, 'o, C>(&'s mut self, condition: &C) -> &'o mut Self
+    where
+        's: 'o,
+        C: Condition,
+    {
+        self.having = track(&mut self.error, condition.format(&mut self.root)).flatten();
+        self
+    }
+    fn windows<'s, 'o>(&'s mut self, windows: &[(String, Window)]) -> &'o mut Self
+    where
+        's: 'o,
+    {
+        if windows.is_empty() {
+            self.windows = None;
+            return self;
+        }
+
+        let definitions = windows
+            .iter()
+            .map(|(name, window)| {
+                window_spec(&mut self.root, window).map(|spec| format!("{} AS ({})", name, spec))
+            })
+            .collect::<Result<Vec<_>, _>>();
+
+        self.windows = track(&mut self.error, definitions).map(|d| Sql::new(d.join(",")));
+        self
+    }
+    fn order_by<'s, 'o>(&'s mut self, order: &[OrderBy]) -> &'o mut Self
+    where
+        's: 'o,
+    {
+        if order.is_empty() {
+            self.order = None;
+            return self;
+        }
+
+        self.order = track(&mut self.error, order_keys(&mut self.root, order)).map(Sql::new);
+        self
+    }
+    fn limit<'s, 'o>(&'s mut self, limit: Option<u64>) -> &'o mut Self
+    where
+        's: 'o,
+    {
+        self.limit = limit;
+        self
+    }
+    fn offset<'s, 'o>(&'s mut self, offset: Option<u64>) -> &'o mut Self
+    where
+        's: 'o,
+    {
+        self.offset = offset;
+        self
+    }
+    fn locking<'s, 'o>(&'s mut self, locking: Option<&Locking>) -> &'o mut Self
+    where
+        's: 'o,
+    {
+        // SQLite locks the entire Database instead of single Rows, which can be done using
+        // BEGIN IMMEDIATE or BEGIN EXCLUSIVE
+        if let Some(locking) = locking {
+            let mut feature = match locking.strength() {
+                LockStrength::Update => "FOR UPDATE".to_string(),
+                LockStrength::Share => "FOR SHARE".to_string(),
+            };
+            if !locking.tables().is_empty() {
+                feature.push_str(" OF ");
+                feature.push_str(&locking.tables().join(","));
+            }
+            match locking.wait() {
+                LockWait::Wait => {}
+                LockWait::NoWait => feature.push_str(" NOWAIT"),
+                LockWait::SkipLocked => feature.push_str(" SKIP LOCKED"),
+            };
+            self.error
+                .get_or_insert(FormatError::Unsupported { feature });
+        }
         self
     }
 }
 
-/// The Builder for Fields
-pub struct SqliteFieldsBuilder {
-    entries: Vec<String>,
+/// The Builder for Joins
+pub struct SqliteJoinBuilder {
+    kind: JoinKind,
+    left: Option<Sql>,
+    right: Option<Sql>,
+    condition: Option<Sql>,
+    error: Option<FormatError>,
+    root: SqliteFormatter,
 }
 
-impl SqliteFieldsBuilder {
-    fn new() -> Self {
+impl SqliteJoinBuilder {
+    fn new(root: &SqliteFormatter) -> Self {
         Self {
-            entries: Vec::new(),
+            kind: JoinKind::Inner,
+            left: None,
+            right: None,
+            condition: None,
+            error: None,
+            root: root.duplicate(),
         }
     }
 }
 
-impl FmtBuilder for SqliteFieldsBuilder {
-    fn finish(&mut self) -> Sql {
-        let raw_inner = self
-            .entries
-            .iter()
-            .map(|s| s.as_str())
-            .intersperse(",")
-            .collect::<String>();
+impl FmtBuilder for SqliteJoinBuilder {
+    fn finish(&mut self) -> Result<Sql, FormatError> {
+        if let Some(err) = self.error.take() {
+            return Err(err);
+        }
+
+        let left_str = self.left.as_ref().expect("The Left Table should be set");
+        let right_str = self.right.as_ref().expect("The Right Table should be set");
+
+        let kind_str = match self.kind {
+            JoinKind::Inner => "INNER JOIN",
+            JoinKind::Left => "LEFT JOIN",
+            JoinKind::Right => "RIGHT JOIN",
+            JoinKind::Full => "FULL JOIN",
+            JoinKind::Cross => "CROSS JOIN",
+        };
+
+        match self.condition.as_ref() {
+            Some(cond) if !cond.is_empty() && self.kind != JoinKind::Cross => {
+                let raw_str = format!("{} {} {} ON {}", left_str, kind_str, right_str, cond);
+                Ok(Sql::new(raw_str))
+            }
+            _ => {
+                let raw_str = format!("{} {} {}", left_str, kind_str, right_str);
+                Ok(Sql::new(raw_str))
+            }
+        }
+    }
+}
+impl JoinBuilder for SqliteJoinBuilder {
+    fn kind<'s, 'o>(&'s mut self, kind: JoinKind) -> &'o mut Self
+    where
+        's: 'o,
+    {
+        self.kind = kind;
+        self
+    }
+    fn left<'s, 'o, T>(&'s mut self, table: &T) -> &'o mut Self
+    where
+        's: 'o,
+        T: Table,
+    {
+        self.left = track(&mut self.error, table.format(&mut self.root));
+        self
+    }
+    fn right<'s, 'o, T>(&'s mut self, table: &T) -> &'o mut Self
+    where
+        's: 'o,
+        T: Table,
+    {
+        // A nested Join on the right Side has to be grouped, as its Condition would otherwise be
+        // attached to this Join
+        let result = table.format(&mut self.root);
+        self.right = track(&mut self.error, result).map(|right| match table.is_join() {
+            true => Sql::new(format!("({})", right)),
+            false => right,
+        });
+        self
+    }
+    fn condition<'s, 'o, C>(&'s mut self, condition: &C) -> &'o mut Self
+    where
+        's: 'o,
+        C: Condition,
+    {
+        self.condition = track(&mut self.error, condition.format(&mut self.root)).flatten();
+        self
+    }
+}
+
+/// The Builder for Compounds of Selects
+pub struct SqliteCompoundBuilder {
+    left: Option<Sql>,
+    operator: SetOperator,
+    right: Option<Sql>,
+    error: Option<FormatError>,
+    root: SqliteFormatter,
+}
+
+impl SqliteCompoundBuilder {
+    fn new(root: &SqliteFormatter) -> Self {
+        Self {
+            left: None,
+            operator: SetOperator::Union,
+            right: None,
+            error: None,
+            root: root.duplicate(),
+        }
+    }
 
-        Sql::new(format!("({})", raw_inner))
+    /// Formats one Side of the Compound, which can't be wrapped in Parentheses in SQLite, so its
+    /// Ordering or Limit would either be rejected or applied to the entire Compound
+    fn part<S>(&mut self, statement: &S) -> Option<Sql>
+    where
+        S: Statement,
+    {
+        if statement.is_ordered() {
+            self.error.get_or_insert(FormatError::Unsupported {
+                feature: "ORDER BY, LIMIT or OFFSET in a Part of a Compound".to_string(),
+            });
+            return None;
+        }
+        track(&mut self.error, statement.format(&mut self.root))
     }
 }
-impl FieldsBuilder for SqliteFieldsBuilder {
-    fn add_field(&mut self, name: String) {
-        self.entries.push(name);
+
+impl FmtBuilder for SqliteCompoundBuilder {
+    fn finish(&mut self) -> Result<Sql, FormatError> {
+        if let Some(err) = self.error.take() {
+            return Err(err);
+        }
+
+        let left_str = self.left.as_ref().expect("The Left Side should be set");
+        let right_str = self.right.as_ref().expect("The Right Side should be set");
+
+        let operator_str = match self.operator {
+            SetOperator::Union => "UNION",
+            SetOperator::UnionAll => "UNION ALL",
+            SetOperator::Intersect => "INTERSECT",
+            SetOperator::Except => "EXCEPT",
+        };
+
+        // SQLite does not allow the Parts of a Compound to be wrapped in Parentheses
+        let raw_str = format!("{} {} {}", left_str, operator_str, right_str);
+        Ok(Sql::new(raw_str))
+    }
+}
+impl CompoundBuilder for SqliteCompoundBuilder {
+    fn left<'s, 'o, S>(&'s mut self, statement: &S) -> &'o mut Self
+    where
+        's: 'o,
+        S: Statement,
+    {
+        self.left = self.part(statement);
+        self
+    }
+    fn operator<'s, 'o>(&'s mut self, operator: SetOperator) -> &'o mut Self
+    where
+        's: 'o,
+    {
+        self.operator = operator;
+        self
+    }
+    fn right<'s, 'o, S>(&'s mut self, statement: &S) -> &'o mut Self
+    where
+        's: 'o,
+        S: Statement,
+    {
+        // Compounds are evaluated from left to right, so a nested Compound on the right Side
+        // would be combined with the Result of the left Side instead
+        if !statement.is_select() {
+            self.error.get_or_insert(FormatError::Unsupported {
+                feature: "Compounds or WITH on the right Side of a Compound".to_string(),
+            });
+            return self;
+        }
+        self.right = self.part(statement);
+        self
     }
 }
 
-/// The Builder for a Condition
-pub struct SqliteConditionBuilder {}
+/// The Builder for Statements with a WITH Clause
+pub struct SqliteWithBuilder {
+    recursive: bool,
+    ctes: Vec<String>,
+    statement: Option<Sql>,
+    error: Option<FormatError>,
+    root: SqliteFormatter,
+}
 
-impl SqliteConditionBuilder {
-    fn new() -> Self {
-        Self {}
+impl SqliteWithBuilder {
+    fn new(root: &SqliteFormatter) -> Self {
+        Self {
+            recursive: false,
+            ctes: Vec::new(),
+            statement: None,
+            error: None,
+            root: root.duplicate(),
+        }
     }
 }
 
-impl FmtBuilder for SqliteConditionBuilder {
-    fn finish(&mut self) -> Sql {
-        todo!()
+impl FmtBuilder for SqliteWithBuilder {
+    fn finish(&mut self) -> Result<Sql, FormatError> {
+        if let Some(err) = self.error.take() {
+            return Err(err);
+        }
+
+        let statement_str = self
+            .statement
+            .as_ref()
+            .expect("The Statement should be set");
+        let ctes_str = self
+            .ctes
+            .iter()
+            .map(|c| c.as_str())
+            .intersperse(",")
+            .collect::<String>();
+
+        let raw_str = if self.recursive {
+            format!("WITH RECURSIVE {} {}", ctes_str, statement_str)
+        } else {
+            format!("WITH {} {}", ctes_str, statement_str)
+        };
+        Ok(Sql::new(raw_str))
     }
 }
-impl ConditionBuilder for SqliteConditionBuilder {
-    fn equal(self, left: Sql, right: Sql) -> Sql {
-        Sql::new(format!("{}={}", left, right))
+impl WithBuilder for SqliteWithBuilder {
+    fn recursive<'s, 'o>(&'s mut self, recursive: bool) -> &'o mut Self
+    where
+        's: 'o,
+    {
+        self.recursive = recursive;
+        self
+    }
+    fn cte<'s, 'o, S>(&'s mut self, name: &str, columns: &[String], query: &S) -> &'o mut Self
+    where
+        's: 'o,
+        S: Statement,
+    {
+        if let Some(query_str) = track(&mut self.error, query.format(&mut self.root)) {
+            let cte_str = if columns.is_empty() {
+                format!("{} AS ({})", name, query_str)
+            } else {
+                let columns_str = columns
+                    .iter()
+                    .map(|c| c.as_str())
+                    .intersperse(",")
+                    .collect::<String>();
+                format!("{}({}) AS ({})", name, columns_str, query_str)
+            };
+            self.ctes.push(cte_str);
+        }
+        self
+    }
+    fn statement<'s, 'o, S>(&'s mut self, statement: &S) -> &'o mut Self
+    where
+        's: 'o,
+        S: Statement,
+    {
+        self.statement = track(&mut self.error, statement.format(&mut self.root));
+        self
     }
+}
 
-    fn and(self, left: Sql, right: Sql) -> Sql {
-        Sql::new(format!("({}) AND ({})", left, right))
+/// The Builder for Insert Statements
+pub struct SqliteInsertBuilder {
+    table: Option<Sql>,
+    columns: Vec<String>,
+    rows: Vec<String>,
+    query: Option<Sql>,
+    conflict: Option<Sql>,
+    returning: Option<Sql>,
+    error: Option<FormatError>,
+    root: SqliteFormatter,
+}
+
+impl SqliteInsertBuilder {
+    fn new(root: &SqliteFormatter) -> Self {
+        Self {
+            table: None,
+            columns: Vec::new(),
+            rows: Vec::new(),
+            query: None,
+            conflict: None,
+            returning: None,
+            error: None,
+            root: root.duplicate(),
+        }
     }
 
-    fn or(self, left: Sql, right: Sql) -> Sql {
-        Sql::new(format!("({}) OR ({})", left, right))
+    fn conflict_clause(&mut self, conflict: &OnConflict) -> Result<Sql, FormatError> {
+        let target_str = match conflict.target() {
+            ConflictTarget::Any => String::new(),
+            ConflictTarget::Columns(columns) => format!(" ({})", columns.join(",")),
+            ConflictTarget::Constraint(_) => {
+                return Err(FormatError::Unsupported {
+                    feature: "ON CONFLICT ON CONSTRAINT".to_string(),
+                })
+            }
+        };
+
+        if conflict.assignments().is_empty() {
+            return Ok(Sql::new(format!("ON CONFLICT{} DO NOTHING", target_str)));
+        }
+
+        let assignments_str = assignments(&mut self.root, conflict.assignments())?;
+        let mut raw_str = format!(
+            "ON CONFLICT{} DO UPDATE SET {}",
+            target_str, assignments_str
+        );
+        if let Some(condition) = conflict.update_condition() {
+            let condition_str = condition.format(&mut self.root)?;
+            raw_str.push_str(&format!(" WHERE {}", condition_str));
+        }
+        Ok(Sql::new(raw_str))
     }
 }
+
+impl FmtBuilder for SqliteInsertBuilder {
+    fn finish(&mut self) -> Result<Sql, FormatError> {
+        if let Some(err) = self.error.take() {
+            return Err(err);
+        }
+
+        let table_str = self.table.as_ref().expect("The Table should be set");
+
+        let columns_str = match self.columns.is_empty() {
+            true => String::new(),
+            false => format!(" ({})", self.columns.join(",")),
+        };
+
+        let mut raw_str = match self.query.as_ref() {
+            Some(query) => {
+                let mut raw_str = format!(
+                    "INSERT INTO {}{} SELECT * FROM {}",
+                    table_str, columns_str, query
+                );
+                // Without a WHERE Clause, SQLite would parse the ON CONFLICT as the Constraint of
+                // a Join
+                if self.conflict.is_some() {
+                    raw_str.push_str(" WHERE true");
+                }
+                raw_str
+            }
+            // SQLite does not allow an Upsert after DEFAULT VALUES
+            None if self.columns.is_empty() && self.conflict.is_some() => {
+                return Err(FormatError::Unsupported {
+                    feature: "ON CONFLICT with DEFAULT VALUES".to_string(),
+                })
+            }
+            None if self.columns.is_empty() => {
+                format!("INSERT INTO {} DEFAULT VALUES", table_str)
+            }
+            None => format!(
+                "INSERT INTO {}{} VALUES {}",
+                table_str,
+                columns_str,
+                self.rows.join(",")
+            ),
+        };
+
+        if let Some(conflict) = self.conflict.as_ref() {
+            raw_str.push_str(&format!(" {}", conflict));
+        }
+        if let Some(returning) = self.returning.as_ref().filter(|r| !r.is_empty()) {
+            raw_str.push_str(&format!(" RETURNING {}", returning));
+        }
+
+        Ok(Sql::new(raw_str))
+    }
+}
+impl InsertBuilder for SqliteInsertBuilder {
+    fn table<'s, 'o, T>(&'s mut self, table: &T) -> &'o mut Self
+    where
+        's: 'o,
+        T: Table,
+    {
+        self.table = track(&mut self.error, table.format(&mut self.root));
+        self
+    }
+    fn values<'s, 'o>(&'s mut self, values: &[(String, Expression)]) -> &'o mut Self
+    where
+        's: 'o,
+    {
+        let mut row = Vec::with_capacity(values.len());
+        for (column, value) in values.iter() {
+            if let Some(value_str) = track(&mut self.error, value.format(&mut self.root)) {
+                self.columns.push(column.clone());
+                row.push(String::from(value_str));
+            }
+        }
+        if !row.is_empty() {
+            self.rows.push(format!("({})", row.join(",")));
+        }
+        self
+    }
+    fn rows<'s, 'o>(&'s mut self, columns: &[String], rows: Vec<Vec<Sql>>) -> &'o mut Self
+    where
+        's: 'o,
+    {
+        self.columns = columns.to_vec();
+        self.rows = rows
+            .into_iter()
+            .map(|row| {
+                let row_str = row
+                    .iter()
+                    .map(|v| v.to_string())
+                    .intersperse(",".to_string())
+                    .collect::<String>();
+                format!("({})", row_str)
+            })
+            .collect();
+        self
+    }
+    fn query<'s, 'o, T>(&'s mut self, columns: &[String], query: &T) -> &'o mut Self
+    where
+        's: 'o,
+        T: Table,
+    {
+        self.columns = columns.to_vec();
+        self.query = track(&mut self.error, query.format(&mut self.root));
+        self
+    }
+    fn on_conflict<'s, 'o>(&'s mut self, conflict: Option<&OnConflict>) -> &'o mut Self
+    where
+        's: 'o,
+    {
+        self.conflict = match conflict {
+            Some(conflict) => {
+                let result = self.conflict_clause(conflict);
+                track(&mut self.error, result)
+            }
+            None => None,
+        };
+        self
+    }
+    fn returning<'s, 'o, F>(&'s mut self, fields: &F) -> &'o mut Self
+    where
+        's: 'o,
+        F: Fields,
+    {
+        self.returning = track(&mut self.error, fields.format(&mut self.root));
+        self
+    }
+}
+
+/// The Builder for Update Statements
+pub struct SqliteUpdateBuilder {
+    table: Option<Sql>,
+    assignments: Option<Sql>,
+    condition: Option<Sql>,
+    returning: Option<Sql>,
+    error: Option<FormatError>,
+    root: SqliteFormatter,
+}
+
+impl SqliteUpdateBuilder {
+    fn new(root: &SqliteFormatter) -> Self {
+        Self {
+            table: None,
+            assignments: None,
+            condition: None,
+            returning: None,
+            error: None,
+            root: root.duplicate(),
+        }
+    }
+}
+
+impl FmtBuilder for SqliteUpdateBuilder {
+    fn finish(&mut self) -> Result<Sql, FormatError> {
+        if let Some(err) = self.error.take() {
+            return Err(err);
+        }
+
+        let table_str = self.table.as_ref().expect("The Table should be set");
+        let assignments_str = self
+            .assignments
+            .as_ref()
+            .expect("The Assignments should be set");
+
+        let mut raw_str = format!("UPDATE {} SET {}", table_str, assignments_str);
+        if let Some(cond) = self.condition.as_ref().filter(|c| !c.is_empty()) {
+            raw_str.push_str(&format!(" WHERE {}", cond));
+        }
+        if let Some(returning) = self.returning.as_ref().filter(|r| !r.is_empty()) {
+            raw_str.push_str(&format!(" RETURNING {}", returning));
+        }
+
+        Ok(Sql::new(raw_str))
+    }
+}
+impl UpdateBuilder for SqliteUpdateBuilder {
+    fn table<'s, 'o, T>(&'s mut self, table: &T) -> &'o mut Self
+    where
+        's: 'o,
+        T: Table,
+    {
+        self.table = track(&mut self.error, table.format(&mut self.root));
+        self
+    }
+    fn assignments<'s, 'o>(&'s mut self, assignments: &[(String, Expression)]) -> &'o mut Self
+    where
+        's: 'o,
+    {
+        let result = self::assignments(&mut self.root, assignments);
+        self.assignments = track(&mut self.error, result).map(Sql::new);
+        self
+    }
+    fn condition<'s, 'o, C>(&'s mut self, condition: &C) -> &'o mut Self
+    where
+        's: 'o,
+        C: Condition,
+    {
+        self.condition = track(&mut self.error, condition.format(&mut self.root)).flatten();
+        self
+    }
+    fn returning<'s, 'o, F>(&'s mut self, fields: &F) -> &'o mut Self
+    where
+        's: 'o,
+        F: Fields,
+    {
+        self.returning = track(&mut self.error, fields.format(&mut self.root));
+        self
+    }
+}
+
+/// The Builder for Delete Statements
+pub struct SqliteDeleteBuilder {
+    table: Option<Sql>,
+    condition: Option<Sql>,
+    returning: Option<Sql>,
+    error: Option<FormatError>,
+    root: SqliteFormatter,
+}
+
+impl SqliteDeleteBuilder {
+    fn new(root: &SqliteFormatter) -> Self {
+        Self {
+            table: None,
+            condition: None,
+            returning: None,
+            error: None,
+            root: root.duplicate(),
+        }
+    }
+}
+
+impl FmtBuilder for SqliteDeleteBuilder {
+    fn finish(&mut self) -> Result<Sql, FormatError> {
+        if let Some(err) = self.error.take() {
+            return Err(err);
+        }
+
+        let table_str = self.table.as_ref().expect("The Table should be set");
+
+        let mut raw_str = format!("DELETE FROM {}", table_str);
+        if let Some(cond) = self.condition.as_ref().filter(|c| !c.is_empty()) {
+            raw_str.push_str(&format!(" WHERE {}", cond));
+        }
+        if let Some(returning) = self.returning.as_ref().filter(|r| !r.is_empty()) {
+            raw_str.push_str(&format!(" RETURNING {}", returning));
+        }
+
+        Ok(Sql::new(raw_str))
+    }
+}
+impl DeleteBuilder for SqliteDeleteBuilder {
+    fn table<'s, 'o, T>(&'s mut self, table: &T) -> &'o mut Self
+    where
+        's: 'o,
+        T: Table,
+    {
+        self.table = track(&mut self.error, table.format(&mut self.root));
+        self
+    }
+    fn condition<'s, 'o, C>(&'s mut self, condition: &C) -> &'o mut Self
+    where
+        's: 'o,
+        C: Condition,
+    {
+        self.condition = track(&mut self.error, condition.format(&mut self.root)).flatten();
+        self
+    }
+    fn returning<'s, 'o, F>(&'s mut self, fields: &F) -> &'o mut Self
+    where
+        's: 'o,
+        F: Fields,
+    {
+        self.returning = track(&mut self.error, fields.format(&mut self.root));
+        self
+    }
+}
+
+/// The Builder for Drop Statements
+pub struct SqliteDropBuilder {
+    kind: DropKind,
+    name: Option<String>,
+    if_exists: bool,
+    cascade: bool,
+}
+
+impl SqliteDropBuilder {
+    fn new() -> Self {
+        Self {
+            kind: DropKind::Table,
+            name: None,
+            if_exists: false,
+            cascade: false,
+        }
+    }
+}
+
+impl FmtBuilder for SqliteDropBuilder {
+    fn finish(&mut self) -> Result<Sql, FormatError> {
+        let kind_str = match self.kind {
+            DropKind::Table => "TABLE",
+            DropKind::Index => "INDEX",
+            DropKind::View => "VIEW",
+        };
+        if self.cascade {
+            return Err(FormatError::Unsupported {
+                feature: format!("DROP {} ... CASCADE", kind_str),
+            });
+        }
+
+        let name = self.name.as_ref().expect("The Name should be set");
+        let raw_str = if self.if_exists {
+            format!("DROP {} IF EXISTS {}", kind_str, name)
+        } else {
+            format!("DROP {} {}", kind_str, name)
+        };
+        Ok(Sql::new(raw_str))
+    }
+}
+impl DropBuilder for SqliteDropBuilder {
+    fn kind<'s, 'o>(&'s mut self, kind: DropKind) -> &'o mut Self
+    where
+        's: 'o,
+    {
+        self.kind = kind;
+        self
+    }
+    fn name<'s, 'o>(&'s mut self, name: &str) -> &'o mut Self
+    where
+        's: 'o,
+    {
+        self.name = Some(name.to_string());
+        self
+    }
+    fn if_exists<'s, 'o>(&'s mut self, if_exists: bool) -> &'o mut Self
+    where
+        's: 'o,
+    {
+        self.if_exists = if_exists;
+        self
+    }
+    fn cascade<'s, 'o>(&'s mut self, cascade: bool) -> &'o mut Self
+    where
+        's: 'o,
+    {
+        self.cascade = cascade;
+        self
+    }
+}
+
+/// The Builder for Create Table Statements
+pub struct SqliteCreateTableBuilder {
+    name: Option<String>,
+    definitions: Vec<String>,
+    strict: bool,
+    without_rowid: bool,
+    primary_key: bool,
+    autoincrement: bool,
+    error: Option<FormatError>,
+    root: SqliteFormatter,
+}
+
+impl SqliteCreateTableBuilder {
+    fn new(root: &SqliteFormatter) -> Self {
+        Self {
+            name: None,
+            definitions: Vec::new(),
+            strict: false,
+            without_rowid: false,
+            primary_key: false,
+            autoincrement: false,
+            error: None,
+            root: root.duplicate(),
+        }
+    }
+}
+
+impl FmtBuilder for SqliteCreateTableBuilder {
+    fn finish(&mut self) -> Result<Sql, FormatError> {
+        if let Some(err) = self.error.take() {
+            return Err(err);
+        }
+
+        // Without the Row-ID, the Rows are identified by their Primary Key instead, which can't
+        // be incremented automatically
+        if self.without_rowid && !self.primary_key {
+            return Err(FormatError::Unsupported {
+                feature: "WITHOUT ROWID on a Table without a PRIMARY KEY".to_string(),
+            });
+        }
+        if self.without_rowid && self.autoincrement {
+            return Err(FormatError::Unsupported {
+                feature: "AUTOINCREMENT on a WITHOUT ROWID Table".to_string(),
+            });
+        }
+
+        let name = self.name.as_ref().expect("The Name should be set");
+        let mut raw_str = format!("CREATE TABLE {} ({})", name, self.definitions.join(","));
+
+        let mut options = Vec::new();
+        if self.strict {
+            options.push("STRICT");
+        }
+        if self.without_rowid {
+            options.push("WITHOUT ROWID");
+        }
+        if !options.is_empty() {
+            raw_str.push(' ');
+            raw_str.push_str(&options.join(", "));
+        }
+
+        Ok(Sql::new(raw_str))
+    }
+}
+impl CreateTableBuilder for SqliteCreateTableBuilder {
+    fn name<'s, 'o>(&'s mut self, name: &str) -> &'o mut Self
+    where
+        's: 'o,
+    {
+        self.name = Some(name.to_string());
+        self
+    }
+    fn columns<'s, 'o>(&'s mut self, columns: &[Column]) -> &'o mut Self
+    where
+        's: 'o,
+    {
+        for column in columns.iter() {
+            self.primary_key |= column.is_primary_key() || column.is_autoincrement();
+            self.autoincrement |= column.is_autoincrement();
+
+            let result = column_definition(&mut self.root, column);
+            if let Some(column_str) = track(&mut self.error, result) {
+                self.definitions.push(column_str);
+            }
+        }
+        self
+    }
+    fn constraints<'s, 'o>(&'s mut self, constraints: &[Constraint]) -> &'o mut Self
+    where
+        's: 'o,
+    {
+        for constraint in constraints.iter() {
+            self.primary_key |= matches!(constraint.kind(), ConstraintKind::PrimaryKey(_));
+
+            let result = table_constraint(&mut self.root, constraint);
+            if let Some(constraint_str) = track(&mut self.error, result) {
+                self.definitions.push(constraint_str);
+            }
+        }
+        self
+    }
+    fn strict<'s, 'o>(&'s mut self, strict: bool) -> &'o mut Self
+    where
+        's: 'o,
+    {
+        self.strict = strict;
+        self
+    }
+    fn without_rowid<'s, 'o>(&'s mut self, without_rowid: bool) -> &'o mut Self
+    where
+        's: 'o,
+    {
+        self.without_rowid = without_rowid;
+        self
+    }
+}
+
+/// The Builder for Alter Table Statements
+pub struct SqliteAlterTableBuilder {
+    table: Option<String>,
+    operations: Vec<AlterOperation>,
+    definition: Option<CreateTable>,
+    foreign_keys: bool,
+    restore: Sql,
+    root: SqliteFormatter,
+}
+
+impl SqliteAlterTableBuilder {
+    fn new(root: &SqliteFormatter) -> Self {
+        Self {
+            table: None,
+            operations: Vec::new(),
+            definition: None,
+            foreign_keys: false,
+            restore: Sql::new(""),
+            root: root.duplicate(),
+        }
+    }
+
+    /// Rebuilds the Table following the Procedure documented by SQLite, which creates a new Table
+    /// with the changed Structure, copies over all the Rows, drops the old Table, renames the new
+    /// Table and finally recreates its Indexes and Triggers.
+    ///
+    /// The Steps run inside of a Savepoint instead of their own Transaction, so that the Rebuild
+    /// can also be part of a larger Transaction. Enforced Foreign Keys are turned off during the
+    /// Rebuild, which only works outside of a Transaction, and are otherwise left alone.
+    fn rebuild(&mut self, table: &str, definition: &CreateTable) -> Result<Sql, FormatError> {
+        let (altered, copied) = alter_table::rebuild(definition, &self.operations)?;
+        let savepoint = format!("alter_{}", table);
+        let temporary = format!("new_{}", table);
+
+        let create = self
+            .root
+            .create_table()
+            .name(&temporary)
+            .columns(altered.column_definitions())
+            .constraints(altered.constraints())
+            .strict(altered.is_strict())
+            .without_rowid(altered.is_without_rowid())
+            .finish()?;
+
+        let mut statements = Vec::new();
+        if self.foreign_keys {
+            statements.push("PRAGMA foreign_keys=OFF".to_string());
+        }
+        statements.extend([format!("SAVEPOINT {}", savepoint), create.into()]);
+        if !copied.is_empty() {
+            let (new_columns, old_columns): (Vec<_>, Vec<_>) = copied.into_iter().unzip();
+            statements.push(format!(
+                "INSERT INTO {} ({}) SELECT {} FROM {}",
+                temporary,
+                new_columns.join(","),
+                old_columns.join(","),
+                table
+            ));
+        }
+        statements.extend([
+            format!("DROP TABLE {}", table),
+            format!("ALTER TABLE {} RENAME TO {}", temporary, altered.name()),
+        ]);
+        if !self.restore.is_empty() {
+            statements.push(self.restore.to_string());
+        }
+        statements.extend([
+            "PRAGMA foreign_key_check".to_string(),
+            format!("RELEASE SAVEPOINT {}", savepoint),
+        ]);
+        if self.foreign_keys {
+            statements.push("PRAGMA foreign_keys=ON".to_string());
+        }
+
+        Ok(Sql::new(statements.join("; ")))
+    }
+}
+
+impl FmtBuilder for SqliteAlterTableBuilder {
+    fn finish(&mut self) -> Result<Sql, FormatError> {
+        let table = self.table.clone().expect("The Table should be set");
+
+        // Constraints and the Types of Columns can't be changed in place
+        let unsupported = self.operations.iter().find_map(|op| match op {
+            AlterOperation::AlterColumnType(..) => Some("ALTER COLUMN ... TYPE"),
+            AlterOperation::AddConstraint(_) => Some("ADD CONSTRAINT"),
+            AlterOperation::DropConstraint(_) => Some("DROP CONSTRAINT"),
+            _ => None,
+        });
+        if let Some(feature) = unsupported {
+            return match self.definition.take() {
+                Some(definition) => self.rebuild(&table, &definition),
+                None => Err(FormatError::Unsupported {
+                    feature: format!("{} without the Definition of the Table", feature),
+                }),
+            };
+        }
+
+        let mut current = table;
+        let mut statements = Vec::with_capacity(self.operations.len());
+        for operation in self.operations.iter() {
+            let statement = match operation {
+                AlterOperation::AddColumn(column, ty) => format!(
+                    "ALTER TABLE {} ADD COLUMN {} {}",
+                    current,
+                    column,
+                    type_name(ty)
+                ),
+                AlterOperation::DropColumn(column) => {
+                    format!("ALTER TABLE {} DROP COLUMN {}", current, column)
+                }
+                AlterOperation::RenameColumn { from, to } => {
+                    format!("ALTER TABLE {} RENAME COLUMN {} TO {}", current, from, to)
+                }
+                AlterOperation::RenameTable(name) => {
+                    let statement = format!("ALTER TABLE {} RENAME TO {}", current, name);
+                    current = name.clone();
+                    statement
+                }
+                _ => unreachable!("Operations that need a Rebuild are handled before"),
+            };
+            statements.push(statement);
+        }
+
+        Ok(Sql::new(statements.join("; ")))
+    }
+}
+impl AlterTableBuilder for SqliteAlterTableBuilder {
+    fn table<'s, 'o>(&'s mut self, table: &str) -> &'o mut Self
+    where
+        's: 'o,
+    {
+        self.table = Some(table.to_string());
+        self
+    }
+    fn operations<'s, 'o>(&'s mut self, operations: &[AlterOperation]) -> &'o mut Self
+    where
+        's: 'o,
+    {
+        self.operations = operations.to_vec();
+        self
+    }
+    fn definition<'s, 'o>(&'s mut self, definition: Option<&CreateTable>) -> &'o mut Self
+    where
+        's: 'o,
+    {
+        self.definition = definition.cloned();
+        self
+    }
+    fn foreign_keys<'s, 'o>(&'s mut self, enabled: bool) -> &'o mut Self
+    where
+        's: 'o,
+    {
+        self.foreign_keys = enabled;
+        self
+    }
+    fn restore<'s, 'o>(&'s mut self, statements: Sql) -> &'o mut Self
+    where
+        's: 'o,
+    {
+        self.restore = statements;
+        self
+    }
+}
+
+/// The Builder for Create Index Statements
+pub struct SqliteCreateIndexBuilder {
+    name: Option<String>,
+    table: Option<String>,
+    unique: bool,
+    keys: Option<String>,
+    condition: Option<Sql>,
+    error: Option<FormatError>,
+    root: SqliteFormatter,
+}
+
+impl SqliteCreateIndexBuilder {
+    fn new(root: &SqliteFormatter) -> Self {
+        Self {
+            name: None,
+            table: None,
+            unique: false,
+            keys: None,
+            condition: None,
+            error: None,
+            root: root.duplicate(),
+        }
+    }
+}
+
+impl FmtBuilder for SqliteCreateIndexBuilder {
+    fn finish(&mut self) -> Result<Sql, FormatError> {
+        if let Some(err) = self.error.take() {
+            return Err(err);
+        }
+
+        let name = self.name.as_ref().expect("The Name should be set");
+        let table = self.table.as_ref().expect("The Table should be set");
+        let keys = self.keys.as_ref().expect("The Keys should be set");
+
+        let unique_str = if self.unique { "UNIQUE " } else { "" };
+        let mut raw_str = format!(
+            "CREATE {}INDEX {} ON {} ({})",
+            unique_str, name, table, keys
+        );
+        if let Some(cond) = self.condition.as_ref().filter(|c| !c.is_empty()) {
+            raw_str.push_str(&format!(" WHERE {}", cond));
+        }
+
+        Ok(Sql::new(raw_str))
+    }
+}
+impl CreateIndexBuilder for SqliteCreateIndexBuilder {
+    fn name<'s, 'o>(&'s mut self, name: &str) -> &'o mut Self
+    where
+        's: 'o,
+    {
+        self.name = Some(name.to_string());
+        self
+    }
+    fn table<'s, 'o>(&'s mut self, table: &str) -> &'o mut Self
+    where
+        's: 'o,
+    {
+        self.table = Some(table.to_string());
+        self
+    }
+    fn unique<'s, 'o>(&'s mut self, unique: bool) -> &'o mut Self
+    where
+        's: 'o,
+    {
+        self.unique = unique;
+        self
+    }
+    fn keys<'s, 'o>(&'s mut self, keys: &[OrderBy]) -> &'o mut Self
+    where
+        's: 'o,
+    {
+        // The Keys of an Index always sort NULL-Values first
+        if keys.iter().any(|k| k.nulls().is_some()) {
+            self.error.get_or_insert(FormatError::Unsupported {
+                feature: "NULLS FIRST/LAST in an Index".to_string(),
+            });
+            return self;
+        }
+
+        self.keys = track(&mut self.error, order_keys(&mut self.root, keys));
+        self
+    }
+    fn condition<'s, 'o, C>(&'s mut self, condition: &C) -> &'o mut Self
+    where
+        's: 'o,
+        C: Condition,
+    {
+        self.condition = track(&mut self.error, condition.format(&mut self.root)).flatten();
+        self
+    }
+}
+
+/// The Builder for Create View Statements
+pub struct SqliteCreateViewBuilder {
+    name: Option<String>,
+    columns: Vec<String>,
+    query: Option<Sql>,
+    error: Option<FormatError>,
+    root: SqliteFormatter,
+}
+
+impl SqliteCreateViewBuilder {
+    fn new(root: &SqliteFormatter) -> Self {
+        Self {
+            name: None,
+            columns: Vec::new(),
+            query: None,
+            error: None,
+            root: root.duplicate(),
+        }
+    }
+}
+
+impl FmtBuilder for SqliteCreateViewBuilder {
+    fn finish(&mut self) -> Result<Sql, FormatError> {
+        if let Some(err) = self.error.take() {
+            return Err(err);
+        }
+
+        let name = self.name.as_ref().expect("The Name should be set");
+        let query = self.query.as_ref().expect("The Query should be set");
+
+        if self.columns.is_empty() {
+            return Ok(Sql::new(format!("CREATE VIEW {} AS {}", name, query)));
+        }
+        Ok(Sql::new(format!(
+            "CREATE VIEW {} ({}) AS {}",
+            name,
+            self.columns.join(","),
+            query
+        )))
+    }
+}
+impl CreateViewBuilder for SqliteCreateViewBuilder {
+    fn name<'s, 'o>(&'s mut self, name: &str) -> &'o mut Self
+    where
+        's: 'o,
+    {
+        self.name = Some(name.to_string());
+        self
+    }
+    fn columns<'s, 'o>(&'s mut self, columns: &[String]) -> &'o mut Self
+    where
+        's: 'o,
+    {
+        self.columns = columns.to_vec();
+        self
+    }
+    fn query<'s, 'o, S>(&'s mut self, query: &S) -> &'o mut Self
+    where
+        's: 'o,
+        S: Statement,
+    {
+        self.query = track(&mut self.error, query.format(&mut self.root));
+        self
+    }
+}
+
+/// The Builder for Statements controlling Transactions
+pub struct SqliteTransactionBuilder {
+    statement: Option<Result<String, FormatError>>,
+}
+
+impl SqliteTransactionBuilder {
+    fn new() -> Self {
+        Self { statement: None }
+    }
+}
+
+impl FmtBuilder for SqliteTransactionBuilder {
+    fn finish(&mut self) -> Result<Sql, FormatError> {
+        self.statement
+            .take()
+            .expect("The Statement should be set")
+            .map(Sql::new)
+    }
+}
+impl TransactionBuilder for SqliteTransactionBuilder {
+    fn begin<'s, 'o>(
+        &'s mut self,
+        mode: Option<TransactionMode>,
+        isolation: Option<IsolationLevel>,
+    ) -> &'o mut Self
+    where
+        's: 'o,
+    {
+        // Transactions in SQLite are always serializable
+        let statement = match isolation {
+            None | Some(IsolationLevel::Serializable) => match mode {
+                None => Ok("BEGIN".to_string()),
+                Some(TransactionMode::Deferred) => Ok("BEGIN DEFERRED".to_string()),
+                Some(TransactionMode::Immediate) => Ok("BEGIN IMMEDIATE".to_string()),
+                Some(TransactionMode::Exclusive) => Ok("BEGIN EXCLUSIVE".to_string()),
+            },
+            Some(_) => Err(FormatError::Unsupported {
+                feature: "Isolation Levels other than SERIALIZABLE".to_string(),
+            }),
+        };
+        self.statement = Some(statement);
+        self
+    }
+    fn commit<'s, 'o>(&'s mut self) -> &'o mut Self
+    where
+        's: 'o,
+    {
+        self.statement = Some(Ok("COMMIT".to_string()));
+        self
+    }
+    fn rollback<'s, 'o>(&'s mut self, savepoint: Option<&str>) -> &'o mut Self
+    where
+        's: 'o,
+    {
+        let statement = match savepoint {
+            Some(name) => format!("ROLLBACK TO SAVEPOINT {}", name),
+            None => "ROLLBACK".to_string(),
+        };
+        self.statement = Some(Ok(statement));
+        self
+    }
+    fn savepoint<'s, 'o>(&'s mut self, name: &str) -> &'o mut Self
+    where
+        's: 'o,
+    {
+        self.statement = Some(Ok(format!("SAVEPOINT {}", name)));
+        self
+    }
+    fn release<'s, 'o>(&'s mut self, name: &str) -> &'o mut Self
+    where
+        's: 'o,
+    {
+        self.statement = Some(Ok(format!("RELEASE SAVEPOINT {}", name)));
+        self
+    }
+}
+
+/// The Builder for Scripts of multiple Statements
+pub struct SqliteScriptBuilder {
+    statements: Vec<String>,
+    error: Option<FormatError>,
+    root: SqliteFormatter,
+}
+
+impl SqliteScriptBuilder {
+    fn new(root: &SqliteFormatter) -> Self {
+        Self {
+            statements: Vec::new(),
+            error: None,
+            root: root.duplicate(),
+        }
+    }
+}
+
+impl FmtBuilder for SqliteScriptBuilder {
+    fn finish(&mut self) -> Result<Sql, FormatError> {
+        if let Some(err) = self.error.take() {
+            return Err(err);
+        }
+
+        Ok(Sql::new(self.statements.join("; ")))
+    }
+}
+impl ScriptBuilder for SqliteScriptBuilder {
+    fn statement<'s, 'o, S>(&'s mut self, statement: &S) -> &'o mut Self
+    where
+        's: 'o,
+        S: Statement,
+    {
+        // Empty Statements, like empty nested Scripts, would only result in a stray Separator
+        let result = statement.format(&mut self.root);
+        if let Some(statement_str) = track(&mut self.error, result).filter(|s| !s.is_empty()) {
+            self.statements.push(statement_str.into());
+        }
+        self
+    }
+}
+
+/// The Builder for Create Statements of FTS5 Tables
+pub struct SqliteCreateFtsTableBuilder {
+    name: Option<String>,
+    arguments: Vec<String>,
+}
+
+impl SqliteCreateFtsTableBuilder {
+    fn new() -> Self {
+        Self {
+            name: None,
+            arguments: Vec::new(),
+        }
+    }
+}
+
+impl FmtBuilder for SqliteCreateFtsTableBuilder {
+    fn finish(&mut self) -> Result<Sql, FormatError> {
+        let name = self.name.as_ref().expect("The Name should be set");
+        Ok(Sql::new(format!(
+            "CREATE VIRTUAL TABLE {} USING fts5({})",
+            name,
+            self.arguments.join(",")
+        )))
+    }
+}
+impl CreateFtsTableBuilder for SqliteCreateFtsTableBuilder {
+    fn name<'s, 'o>(&'s mut self, name: &str) -> &'o mut Self
+    where
+        's: 'o,
+    {
+        self.name = Some(name.to_string());
+        self
+    }
+    fn columns<'s, 'o>(&'s mut self, columns: &[(String, bool)]) -> &'o mut Self
+    where
+        's: 'o,
+    {
+        for (name, indexed) in columns.iter() {
+            if *indexed {
+                self.arguments.push(name.clone());
+            } else {
+                self.arguments.push(format!("{} UNINDEXED", name));
+            }
+        }
+        self
+    }
+    fn options<'s, 'o>(&'s mut self, options: &[(String, String)]) -> &'o mut Self
+    where
+        's: 'o,
+    {
+        for (key, value) in options.iter() {
+            self.arguments.push(format!("{}={}", key, quote(value)));
+        }
+        self
+    }
+}
+
+/// The Builder for Fields
+pub struct SqliteFieldsBuilder {
+    entries: Vec<String>,
+}
+
+impl SqliteFieldsBuilder {
+    fn new() -> Self {
+        Self {
+            entries: Vec::new(),
+        }
+    }
+}
+
+impl FmtBuilder for SqliteFieldsBuilder {
+    fn finish(&mut self) -> Result<Sql, FormatError> {
+        let raw_inner = self
+            .entries
+            .iter()
+            .map(|s| s.as_str())
+            .intersperse(",")
+            .collect::<String>();
+
+        Ok(Sql::new(raw_inner))
+    }
+}
+impl FieldsBuilder for SqliteFieldsBuilder {
+    fn add_field(&mut self, name: String) {
+        self.entries.push(name);
+    }
+}
+
+/// The Builder for a Condition
+pub struct SqliteConditionBuilder {}
+
+impl SqliteConditionBuilder {
+    fn new() -> Self {
+        Self {}
+    }
+}
+
+impl FmtBuilder for SqliteConditionBuilder {
+    fn finish(&mut self) -> Result<Sql, FormatError> {
+        todo!()
+    }
+}
+impl ConditionBuilder for SqliteConditionBuilder {
+    fn equal(self, left: Sql, right: Sql) -> Sql {
+        Sql::new(format!("{}={}", left, right))
+    }
+
+    fn compare(self, left: Sql, comparison: Comparison, right: Sql) -> Sql {
+        let op_str = match comparison {
+            Comparison::Equal => "=",
+            Comparison::NotEqual => "<>",
+            Comparison::Less => "<",
+            Comparison::LessEqual => "<=",
+            Comparison::Greater => ">",
+            Comparison::GreaterEqual => ">=",
+        };
+        Sql::new(format!("{}{}{}", left, op_str, right))
+    }
+
+    fn in_select(self, left: Sql, query: Sql, negated: bool) -> Sql {
+        if negated {
+            Sql::new(format!("{} NOT IN ({})", left, query))
+        } else {
+            Sql::new(format!("{} IN ({})", left, query))
+        }
+    }
+
+    fn exists(self, query: Sql, negated: bool) -> Sql {
+        if negated {
+            Sql::new(format!("NOT EXISTS ({})", query))
+        } else {
+            Sql::new(format!("EXISTS ({})", query))
+        }
+    }
+
+    fn null_check(self, operand: Sql, negated: bool) -> Sql {
+        if negated {
+            Sql::new(format!("{} IS NOT NULL", operand))
+        } else {
+            Sql::new(format!("{} IS NULL", operand))
+        }
+    }
+
+    fn and(self, left: Sql, right: Sql) -> Sql {
+        Sql::new(format!("({}) AND ({})", left, right))
+    }
+
+    fn or(self, left: Sql, right: Sql) -> Sql {
+        Sql::new(format!("({}) OR ({})", left, right))
+    }
+
+    fn full_text_match(self, target: Sql, query: Sql) -> Result<Sql, FormatError> {
+        Ok(Sql::new(format!("{} MATCH {}", target, query)))
+    }
+}
+
+/// The Builder for Expressions
+pub struct SqliteExpressionBuilder {
+    root: SqliteFormatter,
+}
+
+impl SqliteExpressionBuilder {
+    fn new(root: &SqliteFormatter) -> Self {
+        Self {
+            root: root.duplicate(),
+        }
+    }
+}
+
+impl ExpressionBuilder for SqliteExpressionBuilder {
+    fn aggregate(
+        self,
+        function: &AggregateFunction,
+        argument: Option<Sql>,
+        distinct: bool,
+    ) -> Result<Sql, FormatError> {
+        let argument_str = match (argument, distinct) {
+            (Some(arg), true) => format!("DISTINCT {}", arg),
+            (Some(arg), false) => arg.to_string(),
+            (None, _) => "*".to_string(),
+        };
+
+        let raw_str = match function {
+            AggregateFunction::Count => format!("COUNT({})", argument_str),
+            AggregateFunction::Sum => format!("SUM({})", argument_str),
+            AggregateFunction::Avg => format!("AVG({})", argument_str),
+            AggregateFunction::Min => format!("MIN({})", argument_str),
+            AggregateFunction::Max => format!("MAX({})", argument_str),
+            // DISTINCT Aggregates only accept a single Argument
+            AggregateFunction::GroupConcat(_) if distinct => {
+                return Err(FormatError::Unsupported {
+                    feature: "GROUP_CONCAT(DISTINCT ...) with a Separator".to_string(),
+                })
+            }
+            AggregateFunction::GroupConcat(separator) => {
+                format!("GROUP_CONCAT({}, {})", argument_str, quote(separator))
+            }
+        };
+        Ok(Sql::new(raw_str))
+    }
+
+    fn window_function(mut self, function: &WindowFunction) -> Result<Sql, FormatError> {
+        let raw_str = match function {
+            WindowFunction::RowNumber => "ROW_NUMBER()".to_string(),
+            WindowFunction::Rank => "RANK()".to_string(),
+            WindowFunction::DenseRank => "DENSE_RANK()".to_string(),
+            WindowFunction::Lag { column, offset } => format!("LAG({}, {})", column, offset),
+            WindowFunction::Lead { column, offset } => format!("LEAD({}, {})", column, offset),
+            WindowFunction::Aggregate(aggregate) => return aggregate.format_call(&mut self.root),
+        };
+        Ok(Sql::new(raw_str))
+    }
+
+    fn over(mut self, function: Sql, window: &Window) -> Result<Sql, FormatError> {
+        // A Window, that only refers to a named Window, can be used without Parentheses
+        let only_named = window.partitions().is_empty()
+            && window.ordering().is_empty()
+            && window.frame_spec().is_none();
+
+        match window.base() {
+            Some(name) if only_named => Ok(Sql::new(format!("{} OVER {}", function, name))),
+            _ => {
+                let spec = window_spec(&mut self.root, window)?;
+                Ok(Sql::new(format!("{} OVER ({})", function, spec)))
+            }
+        }
+    }
+
+    fn text(self, value: &str) -> Sql {
+        Sql::new(quote(value))
+    }
+
+    fn excluded(self, column: &str) -> Sql {
+        Sql::new(format!("excluded.{}", column))
+    }
+
+    fn parameter(self) -> Sql {
+        Sql::new("?")
+    }
+
+    fn binary(self, left: Sql, operator: BinaryOperator, right: Sql) -> Sql {
+        let op_str = match operator {
+            BinaryOperator::Add => "+",
+            BinaryOperator::Subtract => "-",
+            BinaryOperator::Multiply => "*",
+            BinaryOperator::Divide => "/",
+            BinaryOperator::Modulo => "%",
+            BinaryOperator::Concat => "||",
+        };
+        Sql::new(format!("{}{}{}", left, op_str, right))
+    }
+
+    fn case(self, operand: Option<Sql>, branches: Vec<(Sql, Sql)>, otherwise: Option<Sql>) -> Sql {
+        let mut raw_str = "CASE".to_string();
+        if let Some(operand) = operand {
+            raw_str.push_str(&format!(" {}", operand));
+        }
+        for (condition, result) in branches {
+            raw_str.push_str(&format!(" WHEN {} THEN {}", condition, result));
+        }
+        if let Some(otherwise) = otherwise {
+            raw_str.push_str(&format!(" ELSE {}", otherwise));
+        }
+        raw_str.push_str(" END");
+
+        Sql::new(raw_str)
+    }
+
+    fn cast(self, operand: Sql, target: &Types) -> Sql {
+        Sql::new(format!("CAST({} AS {})", operand, type_name(target)))
+    }
+
+    fn coalesce(self, values: Vec<Sql>) -> Sql {
+        let values_str = values
+            .iter()
+            .map(|v| v.to_string())
+            .intersperse(", ".to_string())
+            .collect::<String>();
+        Sql::new(format!("COALESCE({})", values_str))
+    }
+
+    fn null_if(self, left: Sql, right: Sql) -> Sql {
+        Sql::new(format!("NULLIF({}, {})", left, right))
+    }
+
+    fn full_text_function(self, table: &str, function: &FtsFunction) -> Result<Sql, FormatError> {
+        let raw_str = match function {
+            FtsFunction::Bm25 { weights } => {
+                let mut arguments = vec![table.to_string()];
+                arguments.extend(weights.iter().map(|w| w.to_string()));
+                format!("bm25({})", arguments.join(", "))
+            }
+            FtsFunction::Highlight {
+                column,
+                open,
+                close,
+            } => format!(
+                "highlight({}, {}, {}, {})",
+                table,
+                column,
+                quote(open),
+                quote(close)
+            ),
+            FtsFunction::Snippet {
+                column,
+                open,
+                close,
+                ellipsis,
+                tokens,
+            } => {
+                // A negative Index lets SQLite pick the Column automatically
+                let column_str = match column {
+                    Some(c) => c.to_string(),
+                    None => "-1".to_string(),
+                };
+                format!(
+                    "snippet({}, {}, {}, {}, {}, {})",
+                    table,
+                    column_str,
+                    quote(open),
+                    quote(close),
+                    quote(ellipsis),
+                    tokens
+                )
+            }
+        };
+        Ok(Sql::new(raw_str))
+    }
+
+    fn json_extract(self, operand: Sql, path: &JsonPath, as_text: bool) -> Sql {
+        let operator = if as_text { "->>" } else { "->" };
+        Sql::new(format!(
+            "{}{}{}",
+            operand,
+            operator,
+            quote(&path.to_string())
+        ))
+    }
+
+    fn json_object(self, entries: Vec<(String, Sql)>) -> Sql {
+        let entries_str = entries
+            .iter()
+            .map(|(k, v)| format!("{}, {}", quote(k), v))
+            .intersperse(", ".to_string())
+            .collect::<String>();
+        Sql::new(format!("json_object({})", entries_str))
+    }
+
+    fn json_array(self, values: Vec<Sql>) -> Sql {
+        let values_str = values
+            .iter()
+            .map(|v| v.to_string())
+            .intersperse(", ".to_string())
+            .collect::<String>();
+        Sql::new(format!("json_array({})", values_str))
+    }
+
+    fn json_each(self, document: Sql, path: Option<&JsonPath>) -> Result<Sql, FormatError> {
+        match path {
+            Some(path) => Ok(Sql::new(format!(
+                "json_each({}, {})",
+                document,
+                quote(&path.to_string())
+            ))),
+            None => Ok(Sql::new(format!("json_each({})", document))),
+        }
+    }
+
+    fn now(self) -> Sql {
+        Sql::new("datetime('now')")
+    }
+
+    fn date_trunc(self, operand: Sql, unit: DateTimeUnit) -> Result<Sql, FormatError> {
+        // SQLite only has Modifiers for the Start of Years, Months and Days, the smaller Units are
+        // truncated by formatting the Timestamp without its remaining Parts
+        let raw_str = match unit {
+            DateTimeUnit::Year => format!("datetime({}, 'start of year')", operand),
+            DateTimeUnit::Month => format!("datetime({}, 'start of month')", operand),
+            DateTimeUnit::Day => format!("datetime({}, 'start of day')", operand),
+            DateTimeUnit::Hour => format!("strftime('%Y-%m-%d %H:00:00', {})", operand),
+            DateTimeUnit::Minute => format!("strftime('%Y-%m-%d %H:%M:00', {})", operand),
+            DateTimeUnit::Second => format!("strftime('%Y-%m-%d %H:%M:%S', {})", operand),
+        };
+        Ok(Sql::new(raw_str))
+    }
+
+    fn date_add(self, operand: Sql, interval: &Interval) -> Result<Sql, FormatError> {
+        let unit_str = match interval.unit() {
+            DateTimeUnit::Year => "years",
+            DateTimeUnit::Month => "months",
+            DateTimeUnit::Day => "days",
+            DateTimeUnit::Hour => "hours",
+            DateTimeUnit::Minute => "minutes",
+            DateTimeUnit::Second => "seconds",
+        };
+        Ok(Sql::new(format!(
+            "datetime({}, '{:+} {}')",
+            operand,
+            interval.amount(),
+            unit_str
+        )))
+    }
+
+    fn date_part(self, operand: Sql, part: DatePart) -> Result<Sql, FormatError> {
+        let format_str = match part {
+            DatePart::Year => "%Y",
+            DatePart::Month => "%m",
+            DatePart::Day => "%d",
+            DatePart::Hour => "%H",
+            DatePart::Minute => "%M",
+            DatePart::Second => "%S",
+            DatePart::DayOfWeek => "%w",
+            DatePart::DayOfYear => "%j",
+        };
+        Ok(Sql::new(format!(
+            "CAST(strftime('{}', {}) AS INTEGER)",
+            format_str, operand
+        )))
+    }
+
+    fn date_format(self, operand: Sql, format: &str) -> Result<Sql, FormatError> {
+        Ok(Sql::new(format!(
+            "strftime({}, {})",
+            quote(format),
+            operand
+        )))
+    }
+
+    fn collate(self, operand: Sql, collation: &Collation) -> Result<Sql, FormatError> {
+        Ok(Sql::new(format!(
+            "{} COLLATE {}",
+            operand,
+            collation_name(collation)
+        )))
+    }
+}
+
+/// The Name of the Type in SQLite
+fn type_name(ty: &Types) -> &'static str {
+    match ty {
+        Types::String => "TEXT",
+        Types::Binary => "BLOB",
+        Types::Integer => "INTEGER",
+        Types::Float => "REAL",
+    }
+}
+
+/// The Name of the Collation in SQLite
+fn collation_name(collation: &Collation) -> &str {
+    match collation {
+        Collation::Binary => "BINARY",
+        Collation::NoCase => "NOCASE",
+        Collation::RTrim => "RTRIM",
+        Collation::Named(name) => name,
+    }
+}
+
+/// Formats the Definition of a single Column of a Table
+fn column_definition(root: &mut SqliteFormatter, column: &Column) -> Result<String, FormatError> {
+    let mut raw_str = format!("{} {}", column.name(), type_name(&column.ty()));
+
+    if column.is_autoincrement() {
+        // Only an alias for the Row-ID can be incremented automatically
+        if column.ty() != Types::Integer {
+            return Err(FormatError::Unsupported {
+                feature: "AUTOINCREMENT on a Column that is not an INTEGER PRIMARY KEY".to_string(),
+            });
+        }
+        raw_str.push_str(" PRIMARY KEY AUTOINCREMENT");
+    } else if column.is_primary_key() {
+        raw_str.push_str(" PRIMARY KEY");
+    }
+
+    if let Some(collation) = column.collation() {
+        raw_str.push_str(&format!(" COLLATE {}", collation_name(collation)));
+    }
+
+    if let Some((expression, storage)) = column.generation() {
+        let storage_str = match storage {
+            Generated::Stored => "STORED",
+            Generated::Virtual => "VIRTUAL",
+        };
+        raw_str.push_str(&format!(
+            " GENERATED ALWAYS AS ({}) {}",
+            expression.format(root)?,
+            storage_str
+        ));
+    }
+
+    Ok(raw_str)
+}
+
+/// Formats a Constraint as Part of the Definition of a Table
+fn table_constraint(
+    root: &mut SqliteFormatter,
+    constraint: &Constraint,
+) -> Result<String, FormatError> {
+    let kind_str = match constraint.kind() {
+        ConstraintKind::PrimaryKey(columns) => format!("PRIMARY KEY ({})", columns.join(",")),
+        ConstraintKind::Unique(columns) => format!("UNIQUE ({})", columns.join(",")),
+        ConstraintKind::ForeignKey {
+            columns,
+            table,
+            references,
+        } => format!(
+            "FOREIGN KEY ({}) REFERENCES {} ({})",
+            columns.join(","),
+            table,
+            references.join(",")
+        ),
+        ConstraintKind::Check(condition) => format!("CHECK ({})", condition.format(root)?),
+    };
+
+    match constraint.name() {
+        Some(name) => Ok(format!("CONSTRAINT {} {}", name, kind_str)),
+        None => Ok(kind_str),
+    }
+}
+
+/// Formats the Assignments of a SET Clause
+fn assignments(
+    root: &mut SqliteFormatter,
+    assignments: &[(String, Expression)],
+) -> Result<String, FormatError> {
+    let mut parts = Vec::with_capacity(assignments.len());
+    for (column, value) in assignments.iter() {
+        let value_str = value.format(root)?;
+        parts.push(format!("{}={}", column, value_str));
+    }
+    Ok(parts.join(","))
+}
+
+/// Formats the Keys of an ORDER BY Clause
+fn order_keys(root: &mut SqliteFormatter, order: &[OrderBy]) -> Result<String, FormatError> {
+    let mut keys = Vec::with_capacity(order.len());
+    for o in order.iter() {
+        let key = o.key().format(root)?;
+        let direction = match o.direction() {
+            Direction::Ascending => "ASC",
+            Direction::Descending => "DESC",
+        };
+        keys.push(match o.nulls() {
+            Some(Nulls::First) => format!("{} {} NULLS FIRST", key, direction),
+            Some(Nulls::Last) => format!("{} {} NULLS LAST", key, direction),
+            None => format!("{} {}", key, direction),
+        });
+    }
+    Ok(keys.join(","))
+}
+
+/// Formats the Definition of a Window, without the surrounding Parentheses
+fn window_spec(root: &mut SqliteFormatter, window: &Window) -> Result<String, FormatError> {
+    let mut parts = Vec::new();
+
+    if let Some(base) = window.base() {
+        parts.push(base.to_string());
+    }
+
+    if !window.partitions().is_empty() {
+        let partition_str = window
+            .partitions()
+            .iter()
+            .map(|p| p.as_str())
+            .intersperse(",")
+            .collect::<String>();
+        parts.push(format!("PARTITION BY {}", partition_str));
+    }
+
+    if !window.ordering().is_empty() {
+        parts.push(format!("ORDER BY {}", order_keys(root, window.ordering())?));
+    }
+
+    if let Some(frame) = window.frame_spec() {
+        let units = match frame.units() {
+            FrameUnits::Rows => "ROWS",
+            FrameUnits::Range => "RANGE",
+            FrameUnits::Groups => "GROUPS",
+        };
+        let bound = |bound: FrameBound| match bound {
+            FrameBound::UnboundedPreceding => "UNBOUNDED PRECEDING".to_string(),
+            FrameBound::Preceding(n) => format!("{} PRECEDING", n),
+            FrameBound::CurrentRow => "CURRENT ROW".to_string(),
+            FrameBound::Following(n) => format!("{} FOLLOWING", n),
+            FrameBound::UnboundedFollowing => "UNBOUNDED FOLLOWING".to_string(),
+        };
+
+        match frame.end() {
+            Some(end) => parts.push(format!(
+                "{} BETWEEN {} AND {}",
+                units,
+                bound(frame.start()),
+                bound(end)
+            )),
+            None => parts.push(format!("{} {}", units, bound(frame.start()))),
+        };
+    }
+
+    Ok(parts.join(" "))
+}
+
+/// Quotes the given Value as a String-Literal
+fn quote(value: &str) -> String {
+    format!("'{}'", value.replace('\'', "''"))
+}
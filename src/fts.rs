@@ -0,0 +1,312 @@
+use crate::{
+    fmt::{
+        self, ConditionBuilder, CreateFtsTableBuilder, ExpressionBuilder, FieldsBuilder,
+        FmtBuilder, FormatError,
+    },
+    sql::Sql,
+    verify::{RootTableDefinitions, Scope, VerifyCondition, VerifyError},
+    Condition, Expression, Fields, Statement,
+};
+
+/// Creates a new Table for Full-Text Search, like SQLite's FTS5 Tables.
+///
+/// Besides the configured Columns, the Table also has a hidden Column with the Name of the Table,
+/// which can be used to search all the Columns at once, and a hidden `rank` Column.
+pub struct CreateFtsTable {
+    name: String,
+    /// A List of (Name, indexed) Tuples representing the Columns
+    columns: Vec<(String, bool)>,
+    options: Vec<(String, String)>,
+}
+
+impl CreateFtsTable {
+    /// Creates a new Table where all the Columns are indexed
+    pub fn new<N, I, C>(name: N, columns: I) -> Self
+    where
+        N: Into<String>,
+        I: IntoIterator<Item = C>,
+        C: Into<String>,
+    {
+        Self {
+            name: name.into(),
+            columns: columns.into_iter().map(|c| (c.into(), true)).collect(),
+            options: Vec::new(),
+        }
+    }
+
+    /// Adds a new Column, which is stored in the Table but can't be searched
+    pub fn unindexed<N>(mut self, column: N) -> Self
+    where
+        N: Into<String>,
+    {
+        self.columns.push((column.into(), false));
+        self
+    }
+
+    /// Sets an Option of the Table, like `prefix` or `content_rowid`
+    pub fn option<K, V>(mut self, key: K, value: V) -> Self
+    where
+        K: Into<String>,
+        V: Into<String>,
+    {
+        self.options.push((key.into(), value.into()));
+        self
+    }
+
+    /// Configures the Tokenizer, like `porter unicode61`
+    pub fn tokenize<V>(self, tokenizer: V) -> Self
+    where
+        V: Into<String>,
+    {
+        self.option("tokenize", tokenizer)
+    }
+
+    /// Only indexes the Content of the other Table instead of storing its own Copy
+    pub fn content<V>(self, table: V) -> Self
+    where
+        V: Into<String>,
+    {
+        self.option("content", table)
+    }
+
+    /// Adds the Table to the Definitions, including its hidden Columns, so that it can be used by
+    /// the following Queries
+    pub fn register(&self, roots: &mut RootTableDefinitions) {
+        let fields = self
+            .columns
+            .iter()
+            .map(|(c, _)| c.clone())
+            .chain([self.name.clone(), "rank".to_string()])
+            .collect();
+        roots.add_table(self.name.clone(), fields);
+    }
+}
+
+impl Statement for CreateFtsTable {
+    fn format<F>(&self, fmt: &mut F) -> Result<Sql, FormatError>
+    where
+        F: crate::fmt::Formatter,
+    {
+        fmt.create_fts_table()
+            .name(&self.name)
+            .columns(&self.columns)
+            .options(&self.options)
+            .finish()
+    }
+}
+
+/// Checks if the Column, or the Table to search all its Columns, matches the Full-Text Query
+pub struct FtsMatch {
+    target: String,
+    query: Expression,
+}
+
+impl FtsMatch {
+    /// Creates a new Condition, that searches the Target for the Query
+    pub fn new<N>(target: N, query: Expression) -> Self
+    where
+        N: Into<String>,
+    {
+        Self {
+            target: target.into(),
+            query,
+        }
+    }
+}
+
+impl Condition for FtsMatch {
+    fn format<F>(&self, fmt: &mut F) -> Result<Option<Sql>, FormatError>
+    where
+        F: fmt::Formatter,
+    {
+        let target = Sql::new(self.target.as_str());
+        let query = self.query.format(fmt)?;
+
+        fmt.condition().full_text_match(target, query).map(Some)
+    }
+}
+
+impl VerifyCondition for FtsMatch {
+    fn verify(&self, scope: &Scope<'_>, roots: &RootTableDefinitions) -> Result<(), VerifyError> {
+        scope.resolve(&self.target)?;
+        self.query.verify(scope, roots)
+    }
+}
+
+/// The auxiliary Functions for the Results of a Full-Text Search
+#[derive(Debug, Clone, PartialEq)]
+pub enum FtsFunction {
+    /// The Relevance of the Row, where smaller Values are more relevant
+    Bm25 {
+        /// The Weights of the Columns in Order, which are all 1 if empty
+        weights: Vec<f64>,
+    },
+    /// The Value of the Column with all the matched Terms surrounded by the Markers
+    Highlight {
+        /// The Index of the Column
+        column: usize,
+        /// The Text inserted before every Match
+        open: String,
+        /// The Text inserted after every Match
+        close: String,
+    },
+    /// A short Fragment of the Column with all the matched Terms surrounded by the Markers
+    Snippet {
+        /// The Index of the Column, None to select it automatically
+        column: Option<usize>,
+        /// The Text inserted before every Match
+        open: String,
+        /// The Text inserted after every Match
+        close: String,
+        /// The Text added if the Fragment does not start or end with the Column
+        ellipsis: String,
+        /// The maximum Number of Tokens in the Fragment
+        tokens: u8,
+    },
+}
+
+/// A Call to one of the auxiliary Functions of a Full-Text Search, which can be selected like any
+/// other Field
+#[derive(Debug, Clone, PartialEq)]
+pub struct FtsCall {
+    table: String,
+    function: FtsFunction,
+    alias: Option<String>,
+}
+
+impl FtsCall {
+    /// Creates a new Call of the Function for the searched Table
+    pub fn new<N>(table: N, function: FtsFunction) -> Self
+    where
+        N: Into<String>,
+    {
+        Self {
+            table: table.into(),
+            function,
+            alias: None,
+        }
+    }
+
+    /// `bm25(table)`
+    pub fn bm25<N>(table: N) -> Self
+    where
+        N: Into<String>,
+    {
+        Self::new(
+            table,
+            FtsFunction::Bm25 {
+                weights: Vec::new(),
+            },
+        )
+    }
+
+    /// `bm25(table, weights...)`
+    pub fn bm25_weighted<N, I>(table: N, weights: I) -> Self
+    where
+        N: Into<String>,
+        I: IntoIterator<Item = f64>,
+    {
+        Self::new(
+            table,
+            FtsFunction::Bm25 {
+                weights: weights.into_iter().collect(),
+            },
+        )
+    }
+
+    /// `highlight(table, column, open, close)`
+    pub fn highlight<N, O, C>(table: N, column: usize, open: O, close: C) -> Self
+    where
+        N: Into<String>,
+        O: Into<String>,
+        C: Into<String>,
+    {
+        Self::new(
+            table,
+            FtsFunction::Highlight {
+                column,
+                open: open.into(),
+                close: close.into(),
+            },
+        )
+    }
+
+    /// `snippet(table, column, open, close, ellipsis, tokens)`
+    pub fn snippet<N, O, C, E>(
+        table: N,
+        column: Option<usize>,
+        open: O,
+        close: C,
+        ellipsis: E,
+        tokens: u8,
+    ) -> Self
+    where
+        N: Into<String>,
+        O: Into<String>,
+        C: Into<String>,
+        E: Into<String>,
+    {
+        Self::new(
+            table,
+            FtsFunction::Snippet {
+                column,
+                open: open.into(),
+                close: close.into(),
+                ellipsis: ellipsis.into(),
+                tokens,
+            },
+        )
+    }
+
+    /// Sets the Name of the resulting Field
+    pub fn alias<N>(mut self, alias: N) -> Self
+    where
+        N: Into<String>,
+    {
+        self.alias = Some(alias.into());
+        self
+    }
+
+    /// The Name of the resulting Field, which is either the configured Alias or the Name of the
+    /// Function itself
+    pub fn name(&self) -> String {
+        if let Some(alias) = self.alias.as_ref() {
+            return alias.clone();
+        }
+
+        match &self.function {
+            FtsFunction::Bm25 { .. } => format!("bm25({})", self.table),
+            FtsFunction::Highlight { .. } => format!("highlight({})", self.table),
+            FtsFunction::Snippet { .. } => format!("snippet({})", self.table),
+        }
+    }
+}
+
+impl Fields for FtsCall {
+    type FieldIter = std::iter::Once<String>;
+
+    fn to_iterator(&self) -> Self::FieldIter {
+        std::iter::once(self.name())
+    }
+
+    fn columns(&self) -> Vec<String> {
+        // The Functions receive the hidden Column named after the Table
+        vec![self.table.clone()]
+    }
+
+    fn format<F>(&self, fmt: &mut F) -> Result<Sql, FormatError>
+    where
+        F: fmt::Formatter,
+    {
+        let call = fmt
+            .expression()
+            .full_text_function(&self.table, &self.function)?;
+
+        let mut fields = fmt.fields();
+        match self.alias.as_ref() {
+            Some(alias) => fields.add_field(format!("{} AS {}", call, alias)),
+            None => fields.add_field(call.into()),
+        };
+        fields.finish()
+    }
+}
@@ -1,10 +1,219 @@
-use crate::Table;
+use std::collections::HashSet;
+
+use crate::{
+    fmt::{FmtBuilder, FormatError, InsertBuilder},
+    sql::Sql,
+    verify::{self, RootTableDefinitions, VerifyError, VerifyReturning, VerifyTable},
+    Expression, Fields, OnConflict, Statement, Table,
+};
 
 /// An Insert Statement
-pub struct Insert<T>
+pub struct Insert<T, R = ()>
+where
+    T: Table,
+    R: Fields,
+{
+    table: T,
+    values: Vec<(String, Expression)>,
+    conflict: Option<OnConflict>,
+    returning: R,
+}
+
+impl<T> Insert<T>
+where
+    T: Table,
+{
+    /// Creates a new Insert of a single Row into the Table, without any Values the Row is filled
+    /// with the Defaults of the Columns
+    pub fn new(table: T) -> Self {
+        Self {
+            table,
+            values: Vec::new(),
+            conflict: None,
+            returning: (),
+        }
+    }
+}
+
+impl<T, R> Insert<T, R>
+where
+    T: Table,
+    R: Fields,
+{
+    /// Sets the Column of the new Row to the Value of the Expression
+    pub fn value<N, E>(mut self, column: N, value: E) -> Self
+    where
+        N: Into<String>,
+        E: Into<Expression>,
+    {
+        self.values.push((column.into(), value.into()));
+        self
+    }
+
+    /// Handles Conflicts with already existing Rows, see [`OnConflict`]
+    pub fn on_conflict(mut self, conflict: OnConflict) -> Self {
+        self.conflict = Some(conflict);
+        self
+    }
+
+    /// Returns the given Fields of the inserted Row
+    pub fn returning<R2>(self, fields: R2) -> Insert<T, R2>
+    where
+        R2: Fields,
+    {
+        Insert {
+            table: self.table,
+            values: self.values,
+            conflict: self.conflict,
+            returning: fields,
+        }
+    }
+}
+
+impl<T, R> Statement for Insert<T, R>
+where
+    T: Table,
+    R: Fields,
+{
+    fn format<F>(&self, fmt: &mut F) -> Result<Sql, FormatError>
+    where
+        F: crate::fmt::Formatter,
+    {
+        fmt.insert()
+            .table(&self.table)
+            .values(&self.values)
+            .on_conflict(self.conflict.as_ref())
+            .returning(&self.returning)
+            .finish()
+    }
+}
+
+impl<T, R> VerifyReturning for Insert<T, R>
+where
+    T: VerifyTable,
+    R: Fields,
+{
+    fn get_returned_fields(
+        &self,
+        roots: &RootTableDefinitions,
+    ) -> Result<HashSet<String>, VerifyError> {
+        let fields = self.table.get_fields(roots)?;
+
+        for (column, _) in self.values.iter() {
+            verify::verify_reference(column, &fields)?;
+        }
+
+        verify::returned_fields(&self.returning, &fields)
+    }
+}
+
+/// An Insert Statement, that inserts all the Rows of a Table or Query, `INSERT INTO ... SELECT`
+pub struct InsertSelect<T, Q, R = ()>
 where
     T: Table,
+    Q: Table,
+    R: Fields,
 {
     table: T,
-    values: Vec<(String, String)>,
+    columns: Vec<String>,
+    query: Q,
+    conflict: Option<OnConflict>,
+    returning: R,
+}
+
+impl<T, Q> InsertSelect<T, Q>
+where
+    T: Table,
+    Q: Table,
+{
+    /// Creates a new Insert of all the Rows of the Query into the Table, where the Columns of the
+    /// Query are assigned to the given Columns in Order
+    pub fn new<I, N>(table: T, columns: I, query: Q) -> Self
+    where
+        I: IntoIterator<Item = N>,
+        N: Into<String>,
+    {
+        Self {
+            table,
+            columns: columns.into_iter().map(|c| c.into()).collect(),
+            query,
+            conflict: None,
+            returning: (),
+        }
+    }
+}
+
+impl<T, Q, R> InsertSelect<T, Q, R>
+where
+    T: Table,
+    Q: Table,
+    R: Fields,
+{
+    /// Handles Conflicts with already existing Rows, see [`OnConflict`]
+    pub fn on_conflict(mut self, conflict: OnConflict) -> Self {
+        self.conflict = Some(conflict);
+        self
+    }
+
+    /// Returns the given Fields of the inserted Rows
+    pub fn returning<R2>(self, fields: R2) -> InsertSelect<T, Q, R2>
+    where
+        R2: Fields,
+    {
+        InsertSelect {
+            table: self.table,
+            columns: self.columns,
+            query: self.query,
+            conflict: self.conflict,
+            returning: fields,
+        }
+    }
+}
+
+impl<T, Q, R> Statement for InsertSelect<T, Q, R>
+where
+    T: Table,
+    Q: Table,
+    R: Fields,
+{
+    fn format<F>(&self, fmt: &mut F) -> Result<Sql, FormatError>
+    where
+        F: crate::fmt::Formatter,
+    {
+        fmt.insert()
+            .table(&self.table)
+            .query(&self.columns, &self.query)
+            .on_conflict(self.conflict.as_ref())
+            .returning(&self.returning)
+            .finish()
+    }
+}
+
+impl<T, Q, R> VerifyReturning for InsertSelect<T, Q, R>
+where
+    T: VerifyTable,
+    Q: VerifyTable,
+    R: Fields,
+{
+    fn get_returned_fields(
+        &self,
+        roots: &RootTableDefinitions,
+    ) -> Result<HashSet<String>, VerifyError> {
+        let fields = self.table.get_fields(roots)?;
+        for column in self.columns.iter() {
+            verify::verify_reference(column, &fields)?;
+        }
+
+        // Without any Columns, all the Columns of the Table are filled
+        let expected = match self.columns.is_empty() {
+            true => self.table.get_columns(roots)?.len(),
+            false => self.columns.len(),
+        };
+        let found = self.query.get_columns(roots)?.len();
+        if expected != found {
+            return Err(VerifyError::ColumnCountMismatch { expected, found });
+        }
+
+        verify::returned_fields(&self.returning, &fields)
+    }
 }
@@ -0,0 +1,136 @@
+use std::collections::HashSet;
+
+use crate::{
+    fmt::{FmtBuilder, FormatError, JoinBuilder},
+    sql::Sql,
+    verify::{RootTableDefinitions, Scope, VerifyCondition, VerifyError, VerifyTable},
+    Condition, Table,
+};
+
+/// The different Kinds of Joins between two Tables
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JoinKind {
+    /// Only keeps the Rows that have a match on both sides
+    Inner,
+    /// Keeps all the Rows of the left side
+    Left,
+    /// Keeps all the Rows of the right side
+    Right,
+    /// Keeps all the Rows of both sides
+    Full,
+    /// The cartesian Product of both sides, does not use a Condition
+    Cross,
+}
+
+/// Joins two Tables together, which then results in a new Table itself
+pub struct Join<L, R, C>
+where
+    L: Table,
+    R: Table,
+    C: Condition,
+{
+    kind: JoinKind,
+    left: L,
+    right: R,
+    condition: C,
+}
+
+impl<L, R, C> Join<L, R, C>
+where
+    L: Table,
+    R: Table,
+    C: Condition,
+{
+    /// Creates a new Join of the given Kind between the two Tables, using the Condition for the
+    /// ON-Clause
+    pub fn new(kind: JoinKind, left: L, right: R, condition: C) -> Self {
+        Self {
+            kind,
+            left,
+            right,
+            condition,
+        }
+    }
+
+    /// Creates a new INNER JOIN
+    pub fn inner(left: L, right: R, condition: C) -> Self {
+        Self::new(JoinKind::Inner, left, right, condition)
+    }
+
+    /// Creates a new LEFT JOIN
+    pub fn left(left: L, right: R, condition: C) -> Self {
+        Self::new(JoinKind::Left, left, right, condition)
+    }
+
+    /// Creates a new RIGHT JOIN
+    pub fn right(left: L, right: R, condition: C) -> Self {
+        Self::new(JoinKind::Right, left, right, condition)
+    }
+
+    /// Creates a new FULL JOIN
+    pub fn full(left: L, right: R, condition: C) -> Self {
+        Self::new(JoinKind::Full, left, right, condition)
+    }
+}
+
+impl<L, R> Join<L, R, ()>
+where
+    L: Table,
+    R: Table,
+{
+    /// Creates a new CROSS JOIN
+    pub fn cross(left: L, right: R) -> Self {
+        Self::new(JoinKind::Cross, left, right, ())
+    }
+}
+
+impl<L, R, C> Table for Join<L, R, C>
+where
+    L: Table,
+    R: Table,
+    C: Condition,
+{
+    fn format<F>(&self, fmt: &mut F) -> Result<Sql, FormatError>
+    where
+        F: crate::fmt::Formatter,
+    {
+        fmt.join()
+            .kind(self.kind)
+            .left(&self.left)
+            .right(&self.right)
+            .condition(&self.condition)
+            .finish()
+    }
+
+    fn is_join(&self) -> bool {
+        true
+    }
+}
+
+impl<L, R, C> VerifyTable for Join<L, R, C>
+where
+    L: VerifyTable,
+    R: VerifyTable,
+    C: VerifyCondition,
+{
+    fn get_fields(&self, roots: &RootTableDefinitions) -> Result<HashSet<String>, VerifyError> {
+        let mut fields = self.left.get_fields(roots)?;
+        fields.extend(self.right.get_fields(roots)?);
+
+        self.condition.verify(&Scope::new(&fields), roots)?;
+
+        Ok(fields)
+    }
+
+    fn get_columns_scoped(
+        &self,
+        roots: &RootTableDefinitions,
+        _: Option<&Scope<'_>>,
+    ) -> Result<Vec<String>, VerifyError> {
+        self.get_fields(roots)?;
+
+        let mut columns = self.left.get_columns(roots)?;
+        columns.extend(self.right.get_columns(roots)?);
+        Ok(columns)
+    }
+}
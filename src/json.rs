@@ -0,0 +1,139 @@
+use std::collections::HashSet;
+
+use crate::{
+    fmt::{self, ExpressionBuilder, FormatError},
+    sql::Sql,
+    verify::{RootTableDefinitions, VerifyError, VerifyTable},
+    Expression, Table,
+};
+
+/// A single Step of a [`JsonPath`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum JsonPathSegment {
+    /// The Member of an Object with the given Key
+    Key(String),
+    /// The Element of an Array at the given Index
+    Index(usize),
+}
+
+/// The Path to a Value inside of a JSON Document, starting at its Root.
+///
+/// The Path is displayed in the SQL/JSON Path Syntax, like `$.tags[0]`, which is understood by
+/// most Dialects, the Formatters may however also convert it into their own Operators.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct JsonPath {
+    segments: Vec<JsonPathSegment>,
+}
+
+impl JsonPath {
+    /// Creates a new Path to the Root of the Document
+    pub fn root() -> Self {
+        Self::default()
+    }
+
+    /// Descends into the Member of an Object with the given Key
+    pub fn key<K>(mut self, key: K) -> Self
+    where
+        K: Into<String>,
+    {
+        self.segments.push(JsonPathSegment::Key(key.into()));
+        self
+    }
+
+    /// Descends into the Element of an Array at the given Index
+    pub fn index(mut self, index: usize) -> Self {
+        self.segments.push(JsonPathSegment::Index(index));
+        self
+    }
+
+    /// The Steps of the Path in Order
+    pub fn segments(&self) -> &[JsonPathSegment] {
+        &self.segments
+    }
+}
+
+impl std::fmt::Display for JsonPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "$")?;
+        for segment in self.segments.iter() {
+            match segment {
+                JsonPathSegment::Key(key) => {
+                    let plain = key.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+                    if plain && !key.is_empty() {
+                        write!(f, ".{}", key)?;
+                    } else {
+                        write!(f, ".\"{}\"", key.replace('"', "\\\""))?;
+                    }
+                }
+                JsonPathSegment::Index(index) => write!(f, "[{}]", index)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A Path with just a single Key, `$.key`
+impl From<&str> for JsonPath {
+    fn from(key: &str) -> Self {
+        Self::root().key(key)
+    }
+}
+impl From<String> for JsonPath {
+    fn from(key: String) -> Self {
+        Self::root().key(key)
+    }
+}
+
+/// The Table of all the Elements of a JSON Array or all the Members of a JSON Object, which can be
+/// used like any other Table, usually joined with the Table containing the Document.
+///
+/// The Table has the Columns `key`, `value`, `type`, `atom`, `id`, `parent`, `fullkey` and
+/// `path`, as defined by SQLite.
+#[derive(Debug, Clone, PartialEq)]
+pub struct JsonEach {
+    document: Expression,
+    path: Option<JsonPath>,
+}
+
+impl JsonEach {
+    /// Iterates over the top-level Elements of the Document
+    pub fn new<E>(document: E) -> Self
+    where
+        E: Into<Expression>,
+    {
+        Self {
+            document: document.into(),
+            path: None,
+        }
+    }
+
+    /// Iterates over the Elements of the Value at the Path inside of the Document
+    pub fn path<P>(mut self, path: P) -> Self
+    where
+        P: Into<JsonPath>,
+    {
+        self.path = Some(path.into());
+        self
+    }
+}
+
+impl Table for JsonEach {
+    fn format<F>(&self, fmt: &mut F) -> Result<Sql, FormatError>
+    where
+        F: fmt::Formatter,
+    {
+        let document = self.document.format(fmt)?;
+        fmt.expression().json_each(document, self.path.as_ref())
+    }
+}
+
+impl VerifyTable for JsonEach {
+    fn get_fields(&self, _: &RootTableDefinitions) -> Result<HashSet<String>, VerifyError> {
+        Ok([
+            "key", "value", "type", "atom", "id", "parent", "fullkey", "path",
+        ]
+        .iter()
+        .map(|f| f.to_string())
+        .collect())
+    }
+}
@@ -4,26 +4,79 @@
 
 use std::collections::HashSet;
 
+use fmt::FormatError;
 use sql::Sql;
 use verify::{RootTableDefinitions, VerifyError, VerifyTable};
 
 pub mod sql;
 
 mod condition;
-pub use condition::{And, Or};
+pub use condition::{And, Comparison, Or};
+mod subquery;
+pub use subquery::{CompareSelect, Exists, InSelect};
 mod fields;
 pub use fields::AllFields;
+mod expression;
+pub use expression::{BinaryOperator, Case, Expression, NamedExpression};
+mod aggregate;
+pub use aggregate::{Aggregate, AggregateFunction};
+mod window;
+pub use window::{Frame, FrameBound, FrameUnits, Over, Window, WindowFunction};
+mod alias;
+pub use alias::Aliased;
+mod join;
+pub use join::{Join, JoinKind};
+mod order;
+pub use order::{Direction, Nulls, OrderBy};
 
 mod delete;
 pub use delete::Delete;
 mod insert;
-pub use insert::Insert;
+pub use insert::{Insert, InsertSelect};
+mod batch;
+pub use batch::{BatchInsert, Batches};
+mod upsert;
+pub use upsert::{ConflictTarget, OnConflict};
 mod select;
-pub use select::Select;
+pub use select::{Distinct, LockStrength, LockWait, Locking, Select};
+mod compound;
+pub use compound::{Compound, SetOperator};
+mod with;
+pub use with::{CommonTableExpressions, Cte, With};
 mod update;
 pub use update::Update;
 mod create_table;
-pub use create_table::CreateTable;
+pub use create_table::{Column, Constraint, ConstraintKind, CreateTable, Generated};
+mod alter_table;
+pub use alter_table::{AlterOperation, AlterTable};
+mod drop;
+pub use drop::{DropKind, DropStatement};
+
+mod create_index;
+pub use create_index::CreateIndex;
+
+mod create_view;
+pub use create_view::CreateView;
+
+mod transaction;
+pub use transaction::{
+    Begin, Commit, IsolationLevel, Release, Rollback, Savepoint, TransactionMode,
+};
+
+mod script;
+pub use script::{Script, Statements};
+
+mod fts;
+pub use fts::{CreateFtsTable, FtsCall, FtsFunction, FtsMatch};
+
+mod json;
+pub use json::{JsonEach, JsonPath, JsonPathSegment};
+
+mod datetime;
+pub use datetime::{DatePart, DateTimeUnit, Interval};
+
+mod collation;
+pub use collation::Collation;
 
 pub mod fmt;
 
@@ -32,55 +85,119 @@ pub mod verify;
 /// This represents a Table in SQL which can actually refer to different underlying thing, like an
 /// actual Table in the Database or the result of a SELECT/JOIN Query.
 pub trait Table {
-    /// Formats the Table using the given Formatter to the final SQL form.
+    /// Formats the Table using the given Formatter to the final SQL form, which can be used
+    /// directly in a FROM-Clause.
     ///
     /// Supplying different formatters allows for adjusting between different SQL Flavors depending
     /// on the actual Target Database
-    fn format<F>(&self, fmt: &mut F) -> Sql
+    fn format<F>(&self, fmt: &mut F) -> Result<Sql, FormatError>
     where
         F: fmt::Formatter;
+
+    /// Whether or not the Table is itself a Join of other Tables
+    fn is_join(&self) -> bool {
+        false
+    }
+
+    /// Gives the current Table a new Name
+    fn alias<N>(self, alias: N) -> Aliased<Self>
+    where
+        Self: Sized,
+        N: Into<String>,
+    {
+        Aliased::new(self, alias)
+    }
+
+    /// Combines the current Table with the other Table using an INNER JOIN
+    fn inner_join<R, C>(self, other: R, condition: C) -> Join<Self, R, C>
+    where
+        Self: Sized,
+        R: Table,
+        C: Condition,
+    {
+        Join::inner(self, other, condition)
+    }
+
+    /// Combines the current Table with the other Table using a LEFT JOIN
+    fn left_join<R, C>(self, other: R, condition: C) -> Join<Self, R, C>
+    where
+        Self: Sized,
+        R: Table,
+        C: Condition,
+    {
+        Join::left(self, other, condition)
+    }
+
+    /// Combines the current Table with the other Table using a RIGHT JOIN
+    fn right_join<R, C>(self, other: R, condition: C) -> Join<Self, R, C>
+    where
+        Self: Sized,
+        R: Table,
+        C: Condition,
+    {
+        Join::right(self, other, condition)
+    }
+
+    /// Combines the current Table with the other Table using a FULL JOIN
+    fn full_join<R, C>(self, other: R, condition: C) -> Join<Self, R, C>
+    where
+        Self: Sized,
+        R: Table,
+        C: Condition,
+    {
+        Join::full(self, other, condition)
+    }
+
+    /// Combines the current Table with the other Table using a CROSS JOIN
+    fn cross_join<R>(self, other: R) -> Join<Self, R, ()>
+    where
+        Self: Sized,
+        R: Table,
+    {
+        Join::cross(self, other)
+    }
 }
 impl Table for &str {
-    fn format<F>(&self, _fmt: &mut F) -> Sql
+    fn format<F>(&self, _fmt: &mut F) -> Result<Sql, FormatError>
     where
         F: fmt::Formatter,
     {
-        Sql::new(*self)
+        Ok(Sql::new(*self))
     }
 }
 impl VerifyTable for &str {
     fn get_fields(&self, roots: &RootTableDefinitions) -> Result<HashSet<String>, VerifyError> {
         let fields = roots
-            .get_table(&self)
+            .get_table(self)
             .ok_or_else(|| VerifyError::UnknownTable {
                 table: self.to_string(),
             })?;
-        Ok(fields.clone())
+        Ok(verify::qualify(self, fields.iter().cloned()))
     }
 }
 impl Table for String {
-    fn format<F>(&self, _: &mut F) -> Sql
+    fn format<F>(&self, _: &mut F) -> Result<Sql, FormatError>
     where
         F: fmt::Formatter,
     {
-        Sql::new(self)
+        Ok(Sql::new(self))
     }
 }
 impl VerifyTable for String {
     fn get_fields(&self, roots: &RootTableDefinitions) -> Result<HashSet<String>, VerifyError> {
         let fields = roots
-            .get_table(&self)
+            .get_table(self)
             .ok_or_else(|| VerifyError::UnknownTable {
                 table: self.to_string(),
             })?;
-        Ok(fields.clone())
+        Ok(verify::qualify(self, fields.iter().cloned()))
     }
 }
 
 /// Generalises over one or multiple Conditions
 pub trait Condition {
     /// Formats the Condition using the provided Formatter
-    fn format<F>(&self, fmt: &mut F) -> Option<Sql>
+    fn format<F>(&self, fmt: &mut F) -> Result<Option<Sql>, FormatError>
     where
         F: fmt::Formatter;
 
@@ -108,11 +225,31 @@ pub trait Fields {
     /// The Iterator over the Fields
     type FieldIter: Iterator<Item = String>;
 
-    /// Obtains an Iterator over the Fields
+    /// Obtains an Iterator over the Names of the resulting Fields
     fn to_iterator(&self) -> Self::FieldIter;
 
+    /// Obtains the Columns of the underlying Table that are used by the Fields
+    fn columns(&self) -> Vec<String> {
+        self.to_iterator().collect()
+    }
+
+    /// Obtains the Columns of the underlying Table that are used outside of an Aggregate-Function
+    fn ungrouped_columns(&self) -> Vec<String> {
+        self.columns()
+    }
+
+    /// Whether or not the Fields contain a Call to an Aggregate-Function
+    fn contains_aggregate(&self) -> bool {
+        false
+    }
+
+    /// Whether or not the Fields select all the Columns of the underlying Table
+    fn selects_all(&self) -> bool {
+        false
+    }
+
     /// Formats the Fields using the provided Formatter
-    fn format<F>(&self, fmt: &mut F) -> Sql
+    fn format<F>(&self, fmt: &mut F) -> Result<Sql, FormatError>
     where
         F: fmt::Formatter;
 }
@@ -121,7 +258,17 @@ pub trait Fields {
 /// like SELECTs, DELETEs, INSERTs, etc.
 pub trait Statement {
     /// Formats the Expression using the provided Formatter
-    fn format<F>(&self, fmt: &mut F) -> Sql
+    fn format<F>(&self, fmt: &mut F) -> Result<Sql, FormatError>
     where
         F: fmt::Formatter;
+
+    /// Whether or not the Statement orders or limits the returned Rows itself
+    fn is_ordered(&self) -> bool {
+        false
+    }
+
+    /// Whether or not the Statement is a single plain Select, unlike Compounds or With-Queries
+    fn is_select(&self) -> bool {
+        false
+    }
 }
@@ -0,0 +1,95 @@
+use crate::{Collation, Expression};
+
+/// The Direction in which the Rows should be sorted
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    /// Sort the Rows in ascending Order
+    Ascending,
+    /// Sort the Rows in descending Order
+    Descending,
+}
+
+/// Where NULL-Values should be placed in the sorted Rows
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Nulls {
+    /// NULL-Values come before all other Values
+    First,
+    /// NULL-Values come after all other Values
+    Last,
+}
+
+/// A single Key of an ORDER BY Clause
+#[derive(Debug, Clone, PartialEq)]
+pub struct OrderBy {
+    key: Expression,
+    direction: Direction,
+    nulls: Option<Nulls>,
+}
+
+impl OrderBy {
+    /// Creates a new Key that sorts by the given Column or Expression in the given Direction
+    pub fn new<N>(key: N, direction: Direction) -> Self
+    where
+        N: Into<Expression>,
+    {
+        Self {
+            key: key.into(),
+            direction,
+            nulls: None,
+        }
+    }
+
+    /// Sorts by the given Column or Expression in ascending Order
+    pub fn asc<N>(key: N) -> Self
+    where
+        N: Into<Expression>,
+    {
+        Self::new(key, Direction::Ascending)
+    }
+
+    /// Sorts by the given Column or Expression in descending Order
+    pub fn desc<N>(key: N) -> Self
+    where
+        N: Into<Expression>,
+    {
+        Self::new(key, Direction::Descending)
+    }
+
+    /// Sorts the Key using the Collation
+    pub fn collate(mut self, collation: Collation) -> Self {
+        self.key = self.key.collate(collation);
+        self
+    }
+
+    /// Places NULL-Values before all other Values
+    pub fn nulls_first(mut self) -> Self {
+        self.nulls = Some(Nulls::First);
+        self
+    }
+
+    /// Places NULL-Values after all other Values
+    pub fn nulls_last(mut self) -> Self {
+        self.nulls = Some(Nulls::Last);
+        self
+    }
+
+    /// The Expression that is being sorted by
+    pub fn key(&self) -> &Expression {
+        &self.key
+    }
+
+    /// The Columns referenced by the Key
+    pub fn columns(&self) -> Vec<String> {
+        self.key.columns()
+    }
+
+    /// The Direction of the Sorting
+    pub fn direction(&self) -> Direction {
+        self.direction
+    }
+
+    /// The Placement of NULL-Values, if explicitly configured
+    pub fn nulls(&self) -> Option<Nulls> {
+        self.nulls
+    }
+}
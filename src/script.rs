@@ -0,0 +1,109 @@
+use crate::{
+    fmt::{FmtBuilder, FormatError, ScriptBuilder},
+    sql::Sql,
+    Statement,
+};
+
+/// Generalises over one or multiple Statements, that are executed in Order
+pub trait Statements {
+    /// Adds all the Statements to the Builder
+    fn add_to<B>(&self, builder: &mut B)
+    where
+        B: ScriptBuilder;
+}
+
+impl<S> Statements for S
+where
+    S: Statement,
+{
+    fn add_to<B>(&self, builder: &mut B)
+    where
+        B: ScriptBuilder,
+    {
+        builder.statement(self);
+    }
+}
+
+/// No Statements at all
+impl Statements for () {
+    fn add_to<B>(&self, _: &mut B)
+    where
+        B: ScriptBuilder,
+    {
+    }
+}
+
+impl<S> Statements for Vec<S>
+where
+    S: Statement,
+{
+    fn add_to<B>(&self, builder: &mut B)
+    where
+        B: ScriptBuilder,
+    {
+        for statement in self.iter() {
+            builder.statement(statement);
+        }
+    }
+}
+
+macro_rules! tuple_statements {
+    ($($name:ident),+) => {
+        #[allow(non_snake_case)]
+        impl<$($name),+> Statements for ($($name,)+)
+        where
+            $($name: Statements),+
+        {
+            fn add_to<B>(&self, builder: &mut B)
+            where
+                B: ScriptBuilder,
+            {
+                let ($($name,)+) = self;
+                $($name.add_to(builder);)+
+            }
+        }
+    };
+}
+
+tuple_statements!(S1, S2);
+tuple_statements!(S1, S2, S3);
+tuple_statements!(S1, S2, S3, S4);
+tuple_statements!(S1, S2, S3, S4, S5);
+tuple_statements!(S1, S2, S3, S4, S5, S6);
+tuple_statements!(S1, S2, S3, S4, S5, S6, S7);
+tuple_statements!(S1, S2, S3, S4, S5, S6, S7, S8);
+
+/// A Sequence of Statements, which are formatted into a single Script, like a Migration that
+/// should be run as a whole.
+///
+/// Scripts are Statements themselves, so they can also be nested.
+pub struct Script<S>
+where
+    S: Statements,
+{
+    statements: S,
+}
+
+impl<S> Script<S>
+where
+    S: Statements,
+{
+    /// Creates a new Script of the Statements
+    pub fn new(statements: S) -> Self {
+        Self { statements }
+    }
+}
+
+impl<S> Statement for Script<S>
+where
+    S: Statements,
+{
+    fn format<F>(&self, fmt: &mut F) -> Result<Sql, FormatError>
+    where
+        F: crate::fmt::Formatter,
+    {
+        let mut builder = fmt.script();
+        self.statements.add_to(&mut builder);
+        builder.finish()
+    }
+}
@@ -1,83 +1,277 @@
 use std::collections::HashSet;
 
 use crate::{
-    fmt::{FmtBuilder, SelectBuilder},
+    fmt::{FmtBuilder, FormatError, SelectBuilder},
     sql::Sql,
-    verify::{RootTableDefinitions, VerifyError, VerifyTable},
-    Condition, Fields, Statement, Table,
+    verify::{self, RootTableDefinitions, Scope, VerifyCondition, VerifyError, VerifyTable},
+    Compound, Condition, Fields, OrderBy, SetOperator, Statement, Table, Window,
 };
 
+/// Determines how duplicate Rows are removed from the Result of a Select
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Distinct {
+    /// Only keeps distinct Rows, `SELECT DISTINCT`
+    Rows,
+    /// Only keeps the first Row for every distinct Combination of the given Columns, `SELECT
+    /// DISTINCT ON (...)`. This is only supported by some Dialects, like PostgreSQL
+    On(Vec<String>),
+}
+
+/// The Kind of Lock acquired on the selected Rows
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LockStrength {
+    /// An exclusive Lock, which allows the Rows to be updated, `FOR UPDATE`
+    Update,
+    /// A shared Lock, which only prevents other Transactions from modifying the Rows, `FOR SHARE`
+    Share,
+}
+
+/// What happens if one of the selected Rows is already locked by another Transaction
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LockWait {
+    /// Waits for the other Transaction to release the Lock
+    Wait,
+    /// Fails immediately, `NOWAIT`
+    NoWait,
+    /// Leaves out the locked Rows, `SKIP LOCKED`
+    SkipLocked,
+}
+
+/// Locks the selected Rows for the Rest of the Transaction, which is only supported by some
+/// Dialects, like PostgreSQL and MySQL
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Locking {
+    strength: LockStrength,
+    tables: Vec<String>,
+    wait: LockWait,
+}
+
+impl Locking {
+    /// Creates a new Lock of the given Strength, which waits for other Locks to be released
+    pub fn new(strength: LockStrength) -> Self {
+        Self {
+            strength,
+            tables: Vec::new(),
+            wait: LockWait::Wait,
+        }
+    }
+
+    /// `FOR UPDATE`
+    pub fn update() -> Self {
+        Self::new(LockStrength::Update)
+    }
+
+    /// `FOR SHARE`
+    pub fn share() -> Self {
+        Self::new(LockStrength::Share)
+    }
+
+    /// Only locks the Rows of the given Table, instead of all the Tables of the Select
+    pub fn of<N>(mut self, table: N) -> Self
+    where
+        N: Into<String>,
+    {
+        self.tables.push(table.into());
+        self
+    }
+
+    /// Fails if any of the Rows is already locked
+    pub fn nowait(mut self) -> Self {
+        self.wait = LockWait::NoWait;
+        self
+    }
+
+    /// Leaves out the Rows that are already locked, which is useful for Job-Queues
+    pub fn skip_locked(mut self) -> Self {
+        self.wait = LockWait::SkipLocked;
+        self
+    }
+
+    /// The Strength of the Lock
+    pub fn strength(&self) -> LockStrength {
+        self.strength
+    }
+
+    /// The Tables whose Rows are locked, all if empty
+    pub fn tables(&self) -> &[String] {
+        &self.tables
+    }
+
+    /// The Behaviour for already locked Rows
+    pub fn wait(&self) -> LockWait {
+        self.wait
+    }
+}
+
 /// A Select Statement
-pub struct Select<T, F, C>
+pub struct Select<T, F, C, H = ()>
 where
     T: Table,
     F: Fields,
     C: Condition,
+    H: Condition,
 {
     table: T,
     fields: F,
+    distinct: Option<Distinct>,
     condition: C,
+    group_by: Vec<String>,
+    having: H,
+    windows: Vec<(String, Window)>,
+    order: Vec<OrderBy>,
+    limit: Option<u64>,
+    offset: Option<u64>,
+    locking: Option<Locking>,
 }
 
-impl<T, F, C> Table for Select<T, F, C>
+impl<T, F, C, H> Table for Select<T, F, C, H>
 where
     T: Table,
     F: Fields,
     C: Condition,
+    H: Condition,
 {
-    fn format<FF>(&self, fmt: &mut FF) -> Sql
+    fn format<FF>(&self, fmt: &mut FF) -> Result<Sql, FormatError>
     where
         FF: crate::fmt::Formatter,
     {
-        fmt.select()
-            .table(&self.table)
-            .fields(&self.fields)
-            .condition(&self.condition)
-            .finish()
+        let inner = Statement::format(self, fmt)?;
+        Ok(Sql::new(format!("({})", inner)))
     }
 }
-impl<T, F, C> Statement for Select<T, F, C>
+impl<T, F, C, H> Statement for Select<T, F, C, H>
 where
     T: Table,
     F: Fields,
     C: Condition,
+    H: Condition,
 {
-    fn format<FF>(&self, fmt: &mut FF) -> Sql
+    fn format<FF>(&self, fmt: &mut FF) -> Result<Sql, FormatError>
     where
         FF: crate::fmt::Formatter,
     {
         fmt.select()
             .table(&self.table)
             .fields(&self.fields)
+            .distinct(self.distinct.as_ref())
             .condition(&self.condition)
+            .group_by(&self.group_by)
+            .having(&self.having)
+            .windows(&self.windows)
+            .order_by(&self.order)
+            .limit(self.limit)
+            .offset(self.offset)
+            .locking(self.locking.as_ref())
             .finish()
     }
+
+    fn is_ordered(&self) -> bool {
+        !self.order.is_empty() || self.limit.is_some() || self.offset.is_some()
+    }
+
+    fn is_select(&self) -> bool {
+        true
+    }
 }
 
-impl<T, F, C> VerifyTable for Select<T, F, C>
+impl<T, F, C, H> VerifyTable for Select<T, F, C, H>
 where
     T: VerifyTable,
     F: Fields,
-    C: Condition,
+    C: VerifyCondition,
+    H: VerifyCondition,
 {
     fn get_fields(
         &self,
         roots: &RootTableDefinitions,
     ) -> Result<std::collections::HashSet<String>, VerifyError> {
+        self.get_fields_scoped(roots, None)
+    }
+
+    fn get_fields_scoped(
+        &self,
+        roots: &RootTableDefinitions,
+        outer: Option<&Scope<'_>>,
+    ) -> Result<HashSet<String>, VerifyError> {
         let fields = self.table.get_fields(roots)?;
 
-        let mut result = HashSet::new();
-        for field in self.fields.to_iterator() {
+        for field in self.fields.columns() {
             fields
                 .get(&field)
-                .ok_or_else(|| VerifyError::MissingField {
-                    field: field.clone(),
-                })?;
-            result.insert(field);
+                .ok_or(VerifyError::MissingField { field })?;
+        }
+        self.condition
+            .verify(&Scope::enclosed(&fields, outer), roots)?;
+
+        let distinct_on = match self.distinct.as_ref() {
+            Some(Distinct::On(columns)) => columns.as_slice(),
+            _ => &[],
+        };
+        let window_columns = self.windows.iter().flat_map(|(_, w)| w.columns());
+        for column in self
+            .group_by
+            .iter()
+            .chain(distinct_on.iter())
+            .cloned()
+            .chain(window_columns)
+        {
+            verify::verify_reference(&column, &fields)?;
+        }
+
+        // Once the Rows are grouped, every Column outside of an Aggregate needs to be part of the
+        // grouping. SQLite silently picks an arbitrary Row otherwise, while other Databases like
+        // PostgreSQL reject the Query
+        if !self.group_by.is_empty() || self.fields.contains_aggregate() {
+            for field in self.fields.ungrouped_columns() {
+                let grouped = self
+                    .group_by
+                    .iter()
+                    .any(|g| verify::same_column(g, &field, &fields));
+                if !grouped {
+                    return Err(VerifyError::UngroupedField { field });
+                }
+            }
+        }
+
+        // The resulting Columns are named without the qualifying Table
+        let mut result: HashSet<String> = self
+            .fields
+            .to_iterator()
+            .map(|f| verify::unqualified(&f).to_string())
+            .collect();
+        if self.fields.selects_all() {
+            result.extend(fields.iter().filter(|f| !f.contains('.')).cloned());
+        }
+
+        // The Having and the Ordering can use both the Columns of the Table and the selected Fields
+        let available: HashSet<String> = fields.union(&result).cloned().collect();
+        self.having
+            .verify(&Scope::enclosed(&available, outer), roots)?;
+
+        for column in self.order.iter().flat_map(|o| o.columns()) {
+            verify::verify_reference(&column, &available)?;
         }
 
         Ok(result)
     }
+
+    fn get_columns_scoped(
+        &self,
+        roots: &RootTableDefinitions,
+        outer: Option<&Scope<'_>>,
+    ) -> Result<Vec<String>, VerifyError> {
+        self.get_fields_scoped(roots, outer)?;
+
+        let mut columns: Vec<String> = self
+            .fields
+            .to_iterator()
+            .map(|f| verify::unqualified(&f).to_string())
+            .collect();
+        if self.fields.selects_all() {
+            columns.extend(self.table.get_columns(roots)?);
+        }
+        Ok(columns)
+    }
 }
 
 impl<T, F, C> Select<T, F, C>
@@ -91,7 +285,146 @@ where
         Self {
             table,
             fields,
+            distinct: None,
             condition,
+            group_by: Vec::new(),
+            having: (),
+            windows: Vec::new(),
+            order: Vec::new(),
+            limit: None,
+            offset: None,
+            locking: None,
+        }
+    }
+}
+
+impl<T, F, C, H> Select<T, F, C, H>
+where
+    T: Table,
+    F: Fields,
+    C: Condition,
+    H: Condition,
+{
+    /// Only returns distinct Rows
+    pub fn distinct(mut self) -> Self {
+        self.distinct = Some(Distinct::Rows);
+        self
+    }
+
+    /// Only returns the first Row for every distinct Combination of the given Columns, which
+    /// usually should be combined with an ORDER BY starting with the same Columns
+    pub fn distinct_on<I, N>(mut self, columns: I) -> Self
+    where
+        I: IntoIterator<Item = N>,
+        N: Into<String>,
+    {
+        self.distinct = Some(Distinct::On(
+            columns.into_iter().map(|c| c.into()).collect(),
+        ));
+        self
+    }
+
+    /// Adds a new Column to the GROUP BY Clause
+    pub fn group_by<N>(mut self, column: N) -> Self
+    where
+        N: Into<String>,
+    {
+        self.group_by.push(column.into());
+        self
+    }
+
+    /// Sets the Condition for the HAVING Clause, which is applied to the grouped Rows
+    pub fn having<H2>(self, having: H2) -> Select<T, F, C, H2>
+    where
+        H2: Condition,
+    {
+        Select {
+            table: self.table,
+            fields: self.fields,
+            distinct: self.distinct,
+            condition: self.condition,
+            group_by: self.group_by,
+            having,
+            windows: self.windows,
+            order: self.order,
+            limit: self.limit,
+            offset: self.offset,
+            locking: self.locking,
         }
     }
+
+    /// Adds a new named Window, which can then be referred to using [`Window::named`]
+    pub fn window<N>(mut self, name: N, window: Window) -> Self
+    where
+        N: Into<String>,
+    {
+        self.windows.push((name.into(), window));
+        self
+    }
+
+    /// Adds a new Key to the ORDER BY Clause, the Keys are applied in the Order they are added in
+    pub fn order_by(mut self, order: OrderBy) -> Self {
+        self.order.push(order);
+        self
+    }
+
+    /// Limits the Number of Rows returned
+    pub fn limit(mut self, limit: u64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Skips the given Number of Rows before returning any
+    pub fn offset(mut self, offset: u64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Locks the selected Rows, see [`Locking`]
+    pub fn lock(mut self, locking: Locking) -> Self {
+        self.locking = Some(locking);
+        self
+    }
+
+    /// Locks the selected Rows for updating them, `FOR UPDATE`
+    pub fn for_update(self) -> Self {
+        self.lock(Locking::update())
+    }
+
+    /// Locks the selected Rows against Modifications by other Transactions, `FOR SHARE`
+    pub fn for_share(self) -> Self {
+        self.lock(Locking::share())
+    }
+
+    /// Combines the Results of this Select with another Select using UNION
+    pub fn union<O>(self, other: O) -> Compound<Self, O>
+    where
+        O: Statement,
+    {
+        Compound::new(self, SetOperator::Union, other)
+    }
+
+    /// Combines the Results of this Select with another Select using UNION ALL
+    pub fn union_all<O>(self, other: O) -> Compound<Self, O>
+    where
+        O: Statement,
+    {
+        Compound::new(self, SetOperator::UnionAll, other)
+    }
+
+    /// Combines the Results of this Select with another Select using INTERSECT
+    pub fn intersect<O>(self, other: O) -> Compound<Self, O>
+    where
+        O: Statement,
+    {
+        Compound::new(self, SetOperator::Intersect, other)
+    }
+
+    /// Combines the Results of this Select with another Select using EXCEPT
+    pub fn except<O>(self, other: O) -> Compound<Self, O>
+    where
+        O: Statement,
+    {
+        Compound::new(self, SetOperator::Except, other)
+    }
 }
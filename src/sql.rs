@@ -36,9 +36,14 @@ impl From<Sql> for String {
 }
 
 /// SQL Types that can be used for Columns
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Types {
     /// Stores a String
     String,
     /// Stores Binary Data
     Binary,
+    /// Stores a whole Number
+    Integer,
+    /// Stores a floating-point Number
+    Float,
 }
@@ -0,0 +1,195 @@
+use crate::{
+    fmt::{self, ConditionBuilder, FormatError},
+    sql::Sql,
+    verify::{RootTableDefinitions, Scope, VerifyCondition, VerifyError, VerifyTable},
+    Comparison, Condition, Statement,
+};
+
+/// Checks if the Column is contained in the Results of a Sub-Query, `column IN (SELECT ...)`
+pub struct InSelect<S>
+where
+    S: Statement,
+{
+    column: String,
+    query: S,
+    negated: bool,
+}
+
+impl<S> InSelect<S>
+where
+    S: Statement,
+{
+    /// Creates a new IN Condition
+    pub fn new<N>(column: N, query: S) -> Self
+    where
+        N: Into<String>,
+    {
+        Self {
+            column: column.into(),
+            query,
+            negated: false,
+        }
+    }
+
+    /// Creates a new NOT IN Condition
+    pub fn negated<N>(column: N, query: S) -> Self
+    where
+        N: Into<String>,
+    {
+        Self {
+            column: column.into(),
+            query,
+            negated: true,
+        }
+    }
+}
+
+impl<S> Condition for InSelect<S>
+where
+    S: Statement,
+{
+    fn format<F>(&self, fmt: &mut F) -> Result<Option<Sql>, FormatError>
+    where
+        F: fmt::Formatter,
+    {
+        let left = Sql::new(self.column.as_str());
+        let query = self.query.format(fmt)?;
+
+        Ok(Some(fmt.condition().in_select(left, query, self.negated)))
+    }
+}
+
+impl<S> VerifyCondition for InSelect<S>
+where
+    S: Statement + VerifyTable,
+{
+    fn verify(&self, scope: &Scope<'_>, roots: &RootTableDefinitions) -> Result<(), VerifyError> {
+        scope.resolve(&self.column)?;
+        verify_single_column(&self.query, scope, roots)
+    }
+}
+
+/// Checks if a Sub-Query returns any Rows, `EXISTS (SELECT ...)`
+pub struct Exists<S>
+where
+    S: Statement,
+{
+    query: S,
+    negated: bool,
+}
+
+impl<S> Exists<S>
+where
+    S: Statement,
+{
+    /// Creates a new EXISTS Condition
+    pub fn new(query: S) -> Self {
+        Self {
+            query,
+            negated: false,
+        }
+    }
+
+    /// Creates a new NOT EXISTS Condition
+    pub fn negated(query: S) -> Self {
+        Self {
+            query,
+            negated: true,
+        }
+    }
+}
+
+impl<S> Condition for Exists<S>
+where
+    S: Statement,
+{
+    fn format<F>(&self, fmt: &mut F) -> Result<Option<Sql>, FormatError>
+    where
+        F: fmt::Formatter,
+    {
+        let query = self.query.format(fmt)?;
+
+        Ok(Some(fmt.condition().exists(query, self.negated)))
+    }
+}
+
+impl<S> VerifyCondition for Exists<S>
+where
+    S: Statement + VerifyTable,
+{
+    fn verify(&self, scope: &Scope<'_>, roots: &RootTableDefinitions) -> Result<(), VerifyError> {
+        self.query.get_fields_scoped(roots, Some(scope)).map(|_| ())
+    }
+}
+
+/// Compares a Column against the single Value returned by a Sub-Query
+pub struct CompareSelect<S>
+where
+    S: Statement,
+{
+    column: String,
+    comparison: Comparison,
+    query: S,
+}
+
+impl<S> CompareSelect<S>
+where
+    S: Statement,
+{
+    /// Creates a new Comparison, `column <comparison> (SELECT ...)`
+    pub fn new<N>(column: N, comparison: Comparison, query: S) -> Self
+    where
+        N: Into<String>,
+    {
+        Self {
+            column: column.into(),
+            comparison,
+            query,
+        }
+    }
+}
+
+impl<S> Condition for CompareSelect<S>
+where
+    S: Statement,
+{
+    fn format<F>(&self, fmt: &mut F) -> Result<Option<Sql>, FormatError>
+    where
+        F: fmt::Formatter,
+    {
+        let left = Sql::new(self.column.as_str());
+        let query = self.query.format(fmt)?;
+        let right = Sql::new(format!("({})", query));
+
+        Ok(Some(fmt.condition().compare(left, self.comparison, right)))
+    }
+}
+
+impl<S> VerifyCondition for CompareSelect<S>
+where
+    S: Statement + VerifyTable,
+{
+    fn verify(&self, scope: &Scope<'_>, roots: &RootTableDefinitions) -> Result<(), VerifyError> {
+        scope.resolve(&self.column)?;
+        verify_single_column(&self.query, scope, roots)
+    }
+}
+
+/// Makes sure that the Query returns exactly one Column
+fn verify_single_column<S>(
+    query: &S,
+    scope: &Scope<'_>,
+    roots: &RootTableDefinitions,
+) -> Result<(), VerifyError>
+where
+    S: VerifyTable,
+{
+    let columns = query.get_columns_scoped(roots, Some(scope))?;
+    if columns.len() != 1 {
+        return Err(VerifyError::ColumnCountMismatch {
+            expected: 1,
+            found: columns.len(),
+        });
+    }
+    Ok(())
+}
@@ -0,0 +1,177 @@
+use crate::{
+    fmt::{FmtBuilder, FormatError, TransactionBuilder},
+    sql::Sql,
+    Statement,
+};
+
+/// When SQLite acquires the Locks for a Transaction
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransactionMode {
+    /// The Locks are acquired once the Database is first accessed
+    Deferred,
+    /// A write Lock is acquired immediately
+    Immediate,
+    /// An exclusive Lock is acquired immediately, which also prevents other Connections from
+    /// reading
+    Exclusive,
+}
+
+/// The Isolation Levels defined by the SQL Standard
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IsolationLevel {
+    /// Uncommitted Changes of other Transactions are visible
+    ReadUncommitted,
+    /// Only committed Changes of other Transactions are visible
+    ReadCommitted,
+    /// Rows that were read once, don't change during the Transaction
+    RepeatableRead,
+    /// The Transactions behave as if they were executed one after the other
+    Serializable,
+}
+
+/// Starts a new Transaction
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Begin {
+    mode: Option<TransactionMode>,
+    isolation: Option<IsolationLevel>,
+}
+
+impl Begin {
+    /// Starts a new Transaction using the Defaults of the Database
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// `BEGIN DEFERRED`, only supported by SQLite
+    pub fn deferred() -> Self {
+        Self::new().mode(TransactionMode::Deferred)
+    }
+
+    /// `BEGIN IMMEDIATE`, only supported by SQLite
+    pub fn immediate() -> Self {
+        Self::new().mode(TransactionMode::Immediate)
+    }
+
+    /// `BEGIN EXCLUSIVE`, only supported by SQLite
+    pub fn exclusive() -> Self {
+        Self::new().mode(TransactionMode::Exclusive)
+    }
+
+    /// Sets the Mode used for acquiring the Locks
+    pub fn mode(mut self, mode: TransactionMode) -> Self {
+        self.mode = Some(mode);
+        self
+    }
+
+    /// Sets the Isolation Level of the Transaction
+    pub fn isolation(mut self, level: IsolationLevel) -> Self {
+        self.isolation = Some(level);
+        self
+    }
+}
+
+impl Statement for Begin {
+    fn format<F>(&self, fmt: &mut F) -> Result<Sql, FormatError>
+    where
+        F: crate::fmt::Formatter,
+    {
+        fmt.transaction().begin(self.mode, self.isolation).finish()
+    }
+}
+
+/// Commits the current Transaction
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Commit;
+
+impl Statement for Commit {
+    fn format<F>(&self, fmt: &mut F) -> Result<Sql, FormatError>
+    where
+        F: crate::fmt::Formatter,
+    {
+        fmt.transaction().commit().finish()
+    }
+}
+
+/// Reverts the current Transaction or all the Changes since a Savepoint
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Rollback {
+    savepoint: Option<String>,
+}
+
+impl Rollback {
+    /// Reverts the entire current Transaction
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Reverts all the Changes since the Savepoint with the given Name, which stays active
+    pub fn to<N>(savepoint: N) -> Self
+    where
+        N: Into<String>,
+    {
+        Self {
+            savepoint: Some(savepoint.into()),
+        }
+    }
+}
+
+impl Statement for Rollback {
+    fn format<F>(&self, fmt: &mut F) -> Result<Sql, FormatError>
+    where
+        F: crate::fmt::Formatter,
+    {
+        fmt.transaction()
+            .rollback(self.savepoint.as_deref())
+            .finish()
+    }
+}
+
+/// Creates a new named Savepoint in the current Transaction
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Savepoint {
+    name: String,
+}
+
+impl Savepoint {
+    /// Creates a new Savepoint with the given Name
+    pub fn new<N>(name: N) -> Self
+    where
+        N: Into<String>,
+    {
+        Self { name: name.into() }
+    }
+}
+
+impl Statement for Savepoint {
+    fn format<F>(&self, fmt: &mut F) -> Result<Sql, FormatError>
+    where
+        F: crate::fmt::Formatter,
+    {
+        fmt.transaction().savepoint(&self.name).finish()
+    }
+}
+
+/// Removes the named Savepoint, while keeping all the Changes made since it was created
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Release {
+    name: String,
+}
+
+impl Release {
+    /// Releases the Savepoint with the given Name
+    pub fn new<N>(name: N) -> Self
+    where
+        N: Into<String>,
+    {
+        Self { name: name.into() }
+    }
+}
+
+impl Statement for Release {
+    fn format<F>(&self, fmt: &mut F) -> Result<Sql, FormatError>
+    where
+        F: crate::fmt::Formatter,
+    {
+        fmt.transaction().release(&self.name).finish()
+    }
+}
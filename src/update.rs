@@ -1,12 +1,113 @@
-use crate::{Condition, Table};
+use std::collections::HashSet;
+
+use crate::{
+    fmt::{FmtBuilder, FormatError, UpdateBuilder},
+    sql::Sql,
+    verify::{
+        self, RootTableDefinitions, Scope, VerifyCondition, VerifyError, VerifyReturning,
+        VerifyTable,
+    },
+    Condition, Expression, Fields, Statement, Table,
+};
 
 /// A single Update Statement
-pub struct Update<T, C>
+pub struct Update<T, C, R = ()>
 where
     T: Table,
     C: Condition,
+    R: Fields,
 {
     table: T,
     condition: C,
-    values: Vec<(String, String)>,
+    values: Vec<(String, Expression)>,
+    returning: R,
+}
+
+impl<T, C> Update<T, C>
+where
+    T: Table,
+    C: Condition,
+{
+    /// Creates a new Update of the Rows in the Table matching the Condition
+    pub fn new(table: T, condition: C) -> Self {
+        Self {
+            table,
+            condition,
+            values: Vec::new(),
+            returning: (),
+        }
+    }
+}
+
+impl<T, C, R> Update<T, C, R>
+where
+    T: Table,
+    C: Condition,
+    R: Fields,
+{
+    /// Adds a new Assignment to the SET Clause, which sets the Column to the Value of the
+    /// Expression
+    pub fn set<N, E>(mut self, column: N, value: E) -> Self
+    where
+        N: Into<String>,
+        E: Into<Expression>,
+    {
+        self.values.push((column.into(), value.into()));
+        self
+    }
+
+    /// Returns the given Fields of the updated Rows
+    pub fn returning<R2>(self, fields: R2) -> Update<T, C, R2>
+    where
+        R2: Fields,
+    {
+        Update {
+            table: self.table,
+            condition: self.condition,
+            values: self.values,
+            returning: fields,
+        }
+    }
+}
+
+impl<T, C, R> Statement for Update<T, C, R>
+where
+    T: Table,
+    C: Condition,
+    R: Fields,
+{
+    fn format<F>(&self, fmt: &mut F) -> Result<Sql, FormatError>
+    where
+        F: crate::fmt::Formatter,
+    {
+        fmt.update()
+            .table(&self.table)
+            .assignments(&self.values)
+            .condition(&self.condition)
+            .returning(&self.returning)
+            .finish()
+    }
+}
+
+impl<T, C, R> VerifyReturning for Update<T, C, R>
+where
+    T: VerifyTable,
+    C: VerifyCondition,
+    R: Fields,
+{
+    fn get_returned_fields(
+        &self,
+        roots: &RootTableDefinitions,
+    ) -> Result<HashSet<String>, VerifyError> {
+        let fields = self.table.get_fields(roots)?;
+        let scope = Scope::new(&fields);
+
+        for (column, value) in self.values.iter() {
+            verify::verify_reference(column, &fields)?;
+            value.verify(&scope, roots)?;
+        }
+        self.condition.verify(&scope, roots)?;
+
+        verify::returned_fields(&self.returning, &fields)
+    }
 }
@@ -0,0 +1,106 @@
+use crate::Expression;
+
+/// The Rows that are checked for Conflicts with the newly inserted Row
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConflictTarget {
+    /// Any Uniqueness-Constraint of the Table
+    Any,
+    /// The Uniqueness-Constraint on the given Columns
+    Columns(Vec<String>),
+    /// The Constraint with the given Name
+    Constraint(String),
+}
+
+/// Describes what should happen, when an inserted Row conflicts with an already existing Row,
+/// which turns an Insert into an Upsert.
+///
+/// Dialects without `ON CONFLICT` translate this into their own Form, like MySQL's
+/// `ON DUPLICATE KEY UPDATE`, where the Values of the proposed Row are referred to using
+/// [`Expression::excluded`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct OnConflict {
+    target: ConflictTarget,
+    assignments: Vec<(String, Expression)>,
+    condition: Option<Expression>,
+}
+
+impl OnConflict {
+    /// Creates a new Handler for Conflicts on the given Target, which does nothing by default
+    pub fn new(target: ConflictTarget) -> Self {
+        Self {
+            target,
+            assignments: Vec::new(),
+            condition: None,
+        }
+    }
+
+    /// Handles Conflicts on any Uniqueness-Constraint of the Table
+    pub fn any() -> Self {
+        Self::new(ConflictTarget::Any)
+    }
+
+    /// Handles Conflicts on the Uniqueness-Constraint of the given Columns
+    pub fn columns<I, N>(columns: I) -> Self
+    where
+        I: IntoIterator<Item = N>,
+        N: Into<String>,
+    {
+        Self::new(ConflictTarget::Columns(
+            columns.into_iter().map(|c| c.into()).collect(),
+        ))
+    }
+
+    /// Handles Conflicts on the Constraint with the given Name
+    pub fn constraint<N>(name: N) -> Self
+    where
+        N: Into<String>,
+    {
+        Self::new(ConflictTarget::Constraint(name.into()))
+    }
+
+    /// Updates the Column of the existing Row to the Value of the Expression, `DO UPDATE SET`
+    pub fn set<N, E>(mut self, column: N, value: E) -> Self
+    where
+        N: Into<String>,
+        E: Into<Expression>,
+    {
+        self.assignments.push((column.into(), value.into()));
+        self
+    }
+
+    /// Updates the Columns of the existing Row to the Values of the Row that should have been
+    /// inserted, `col = excluded.col`
+    pub fn set_excluded<I, N>(mut self, columns: I) -> Self
+    where
+        I: IntoIterator<Item = N>,
+        N: Into<String>,
+    {
+        for column in columns {
+            let column = column.into();
+            let value = Expression::excluded(column.clone());
+            self.assignments.push((column, value));
+        }
+        self
+    }
+
+    /// Only updates the existing Row if the Condition holds
+    pub fn condition(mut self, condition: Expression) -> Self {
+        self.condition = Some(condition);
+        self
+    }
+
+    /// The Rows that are checked for Conflicts
+    pub fn target(&self) -> &ConflictTarget {
+        &self.target
+    }
+
+    /// The Assignments for updating the existing Row, empty if the Conflict should be ignored
+    pub fn assignments(&self) -> &[(String, Expression)] {
+        &self.assignments
+    }
+
+    /// The Condition for updating the existing Row
+    pub fn update_condition(&self) -> Option<&Expression> {
+        self.condition.as_ref()
+    }
+}
@@ -4,9 +4,24 @@ use std::collections::{HashMap, HashSet};
 
 use crate::{Condition, Fields, Select, Table};
 
+/// The Definition of an Index on one of the Root-Tables
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IndexDefinition {
+    /// The indexed Table
+    pub table: String,
+    /// The Columns referenced by the Keys of the Index, in Order
+    pub columns: Vec<String>,
+    /// Whether or not the Keys are unique across all the Rows
+    pub unique: bool,
+    /// Whether or not the Index only contains the Rows matching a Condition
+    pub partial: bool,
+}
+
 /// Defines the Root-Tables in the Database
+#[derive(Debug, Clone)]
 pub struct RootTableDefinitions {
     inner: HashMap<String, HashSet<String>>,
+    indexes: HashMap<String, IndexDefinition>,
 }
 
 impl RootTableDefinitions {
@@ -14,6 +29,7 @@ impl RootTableDefinitions {
     pub fn new() -> Self {
         RootTableDefinitions {
             inner: HashMap::new(),
+            indexes: HashMap::new(),
         }
     }
 
@@ -32,6 +48,36 @@ impl RootTableDefinitions {
     {
         self.inner.get(name.as_ref())
     }
+
+    /// Adds a new Index Definition
+    pub fn add_index<N>(&mut self, name: N, index: IndexDefinition)
+    where
+        N: Into<String>,
+    {
+        self.indexes.insert(name.into(), index);
+    }
+
+    /// Attempts to load the Definition of an Index with the given Name
+    pub fn get_index<N>(&self, name: N) -> Option<&IndexDefinition>
+    where
+        N: AsRef<str>,
+    {
+        self.indexes.get(name.as_ref())
+    }
+
+    /// All the Indexes on the Table with the given Name together with their Names
+    pub fn indexes_on<'s, N>(
+        &'s self,
+        table: N,
+    ) -> impl Iterator<Item = (&'s str, &'s IndexDefinition)>
+    where
+        N: AsRef<str> + 's,
+    {
+        self.indexes
+            .iter()
+            .filter(move |(_, index)| index.table == table.as_ref())
+            .map(|(name, index)| (name.as_str(), index))
+    }
 }
 
 impl Default for RootTableDefinitions {
@@ -44,6 +90,148 @@ impl Default for RootTableDefinitions {
 pub trait VerifyTable: Table {
     /// Returns the Fields available in this Table
     fn get_fields(&self, roots: &RootTableDefinitions) -> Result<HashSet<String>, VerifyError>;
+
+    /// Returns the Fields of the non-recursive Part of this Table, which are needed to verify
+    /// recursive Common Table Expressions. For most Tables these are the same as the Fields
+    /// returned by [`get_fields`](VerifyTable::get_fields)
+    fn get_anchor_fields(
+        &self,
+        roots: &RootTableDefinitions,
+    ) -> Result<HashSet<String>, VerifyError> {
+        self.get_fields(roots)
+    }
+
+    /// Returns the Fields available in this Table, when it is used as a Sub-Query inside of other
+    /// Queries, whose Fields can then also be referred to by this Table
+    fn get_fields_scoped(
+        &self,
+        roots: &RootTableDefinitions,
+        outer: Option<&Scope<'_>>,
+    ) -> Result<HashSet<String>, VerifyError> {
+        let _ = outer;
+        self.get_fields(roots)
+    }
+
+    /// Returns the Names of the Columns returned by this Table in Order. Unlike the Fields, a Name
+    /// is contained multiple Times if it is shared by multiple Columns, like when selecting both
+    /// `users.id` and `orders.id`, so these should be used for counting the Columns
+    fn get_columns(&self, roots: &RootTableDefinitions) -> Result<Vec<String>, VerifyError> {
+        self.get_columns_scoped(roots, None)
+    }
+
+    /// Returns the Names of the Columns of the non-recursive Part of this Table, see
+    /// [`get_anchor_fields`](VerifyTable::get_anchor_fields)
+    fn get_anchor_columns(&self, roots: &RootTableDefinitions) -> Result<Vec<String>, VerifyError> {
+        self.get_columns(roots)
+    }
+
+    /// Returns the Names of the Columns returned by this Table in Order, when it is used as a
+    /// Sub-Query inside of other Queries
+    fn get_columns_scoped(
+        &self,
+        roots: &RootTableDefinitions,
+        outer: Option<&Scope<'_>>,
+    ) -> Result<Vec<String>, VerifyError> {
+        // Plain Tables have no Order, but their Fields also contain every Column only once
+        let mut columns: Vec<String> = self
+            .get_fields_scoped(roots, outer)?
+            .into_iter()
+            .filter(|f| !(is_identifier(f) && f.contains('.')))
+            .collect();
+        columns.sort();
+        Ok(columns)
+    }
+}
+
+/// The Fields that can be referred to at some Point in a Query, which includes the Fields of all
+/// the enclosing Queries
+#[derive(Debug)]
+pub struct Scope<'s> {
+    fields: &'s HashSet<String>,
+    parent: Option<&'s Scope<'s>>,
+}
+
+impl<'s> Scope<'s> {
+    /// Creates a new Scope, that is not enclosed by any other Query
+    pub fn new(fields: &'s HashSet<String>) -> Self {
+        Self {
+            fields,
+            parent: None,
+        }
+    }
+
+    /// Creates a new Scope for a Sub-Query enclosed by the current Scope
+    pub fn nested(&'s self, fields: &'s HashSet<String>) -> Scope<'s> {
+        Self {
+            fields,
+            parent: Some(self),
+        }
+    }
+
+    /// Creates a new Scope for the given Fields, enclosed by the optional outer Scope
+    pub fn enclosed(fields: &'s HashSet<String>, outer: Option<&'s Scope<'s>>) -> Self {
+        Self {
+            fields,
+            parent: outer,
+        }
+    }
+
+    /// Resolves the referenced Column in the innermost Scope that contains it, like SQL itself.
+    ///
+    /// A Column is reported as ambiguous if its unqualified Name is shared by multiple Tables of
+    /// that Scope, or if it is an unqualified Name that only an outer Scope provides, as this
+    /// usually hides a missing Qualifier in a correlated Sub-Query
+    pub fn resolve(&self, column: &str) -> Result<(), VerifyError> {
+        let qualified = is_identifier(column) && column.contains('.');
+
+        let mut current = Some(self);
+        let mut correlated = false;
+        while let Some(scope) = current {
+            if scope.fields.contains(column) {
+                if !qualified && (correlated || scope.is_shared(column)) {
+                    return Err(VerifyError::AmbiguousField {
+                        field: column.to_string(),
+                    });
+                }
+                return Ok(());
+            }
+            correlated = true;
+            current = scope.parent;
+        }
+
+        Err(VerifyError::MissingField {
+            field: column.to_string(),
+        })
+    }
+
+    /// Whether the unqualified Column is provided by more than one Table of this Scope
+    fn is_shared(&self, column: &str) -> bool {
+        let suffix = format!(".{}", column);
+        self.fields
+            .iter()
+            .filter_map(|f| f.strip_suffix(&suffix))
+            .filter(|table| !table.contains('.'))
+            .count()
+            > 1
+    }
+}
+
+/// An extension Trait for adding the Functionality needed for verifying the Conditions of a Query
+pub trait VerifyCondition: Condition {
+    /// Verifies that the Condition only refers to Fields in the given Scope and that any
+    /// Sub-Queries are valid themselves
+    fn verify(&self, scope: &Scope<'_>, roots: &RootTableDefinitions) -> Result<(), VerifyError>;
+}
+
+/// An extension Trait for verifying Statements that modify a Table, like Inserts, Updates and
+/// Deletes
+pub trait VerifyReturning {
+    /// Verifies the Statement and returns the Fields of the RETURNING Clause, which are empty if
+    /// the Statement does not return any Rows
+    fn get_returned_fields(
+        &self,
+        roots: &RootTableDefinitions,
+    ) -> Result<HashSet<String>, VerifyError>;
 }
 
 /// The Error that could be returned when attempting to verify a Query
@@ -54,22 +242,156 @@ pub enum VerifyError {
         /// The Field that was not found
         field: String,
     },
+    /// A Field was selected outside of an Aggregate-Function, without being part of the GROUP BY
+    /// Clause
+    UngroupedField {
+        /// The Field that was not grouped
+        field: String,
+    },
+    /// Two Queries were combined, but they don't return the same Number of Columns
+    ColumnCountMismatch {
+        /// The Number of Columns that was expected
+        expected: usize,
+        /// The Number of Columns that was actually returned
+        found: usize,
+    },
+    /// A Field was referred to, that is available in multiple Tables or only in an outer Scope
+    /// without being qualified
+    AmbiguousField {
+        /// The Field that was found multiple times
+        field: String,
+    },
     /// A Table was attempted to be used that was not defined
     UnknownTable {
         /// The Name of the Table
         table: String,
     },
+    /// Multiple Columns of a new Table or View share the same Name
+    DuplicateColumn {
+        /// The shared Name
+        column: String,
+    },
+}
+
+/// Extends the given Fields with their qualified Form, like `table.field`, so that they can be
+/// referred to using both
+pub(crate) fn qualify<N, I>(name: N, fields: I) -> HashSet<String>
+where
+    N: AsRef<str>,
+    I: IntoIterator<Item = String>,
+{
+    let name = name.as_ref();
+
+    let mut result = HashSet::new();
+    for field in fields {
+        result.insert(format!("{}.{}", name, field));
+        result.insert(field);
+    }
+    result
+}
+
+/// Checks if the given Part of a Condition is a plain Identifier, which refers to a Column, like
+/// `name` or `table.name`
+pub(crate) fn is_identifier(raw: &str) -> bool {
+    const KEYWORDS: &[&str] = &[
+        "NULL",
+        "TRUE",
+        "FALSE",
+        "CURRENT_DATE",
+        "CURRENT_TIME",
+        "CURRENT_TIMESTAMP",
+    ];
+    if KEYWORDS.iter().any(|k| k.eq_ignore_ascii_case(raw)) {
+        return false;
+    }
+
+    raw.split('.').all(|part| {
+        let mut chars = part.chars();
+        match chars.next() {
+            Some(c) if c.is_ascii_alphabetic() || c == '_' => {
+                chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+            }
+            _ => false,
+        }
+    })
+}
+
+/// Strips the qualifying Table from the Name of a Column, Names of other Expressions are kept as is
+pub(crate) fn unqualified(column: &str) -> &str {
+    if !is_identifier(column) {
+        return column;
+    }
+    match column.rsplit_once('.') {
+        Some((_, name)) => name,
+        None => column,
+    }
+}
+
+/// Whether or not both References refer to the same Column of the Fields, where one of them may
+/// leave out the qualifying Table if the Name can't refer to the Column of another Table
+pub(crate) fn same_column(left: &str, right: &str, fields: &HashSet<String>) -> bool {
+    if left == right {
+        return true;
+    }
+
+    let is_qualified = |name: &str| is_identifier(name) && name.contains('.');
+    let (qualified, plain) = match (is_qualified(left), is_qualified(right)) {
+        (true, false) => (left, right),
+        (false, true) => (right, left),
+        _ => return false,
+    };
+    if unqualified(qualified) != plain {
+        return false;
+    }
+    fields
+        .iter()
+        .filter(|f| is_qualified(f) && unqualified(f) == plain)
+        .all(|f| f == qualified)
+}
+
+/// Verifies the Fields of a RETURNING Clause against the Fields of the modified Table and returns
+/// the Names of the returned Columns
+pub(crate) fn returned_fields<R>(
+    returning: &R,
+    fields: &HashSet<String>,
+) -> Result<HashSet<String>, VerifyError>
+where
+    R: Fields,
+{
+    for column in returning.columns() {
+        verify_reference(&column, fields)?;
+    }
+
+    let mut result: HashSet<String> = returning
+        .to_iterator()
+        .map(|f| unqualified(&f).to_string())
+        .collect();
+    if returning.selects_all() {
+        result.extend(fields.iter().filter(|f| !f.contains('.')).cloned());
+    }
+    Ok(result)
+}
+
+/// Makes sure that the referenced Column is one of the available Fields
+pub(crate) fn verify_reference(column: &str, fields: &HashSet<String>) -> Result<(), VerifyError> {
+    if !fields.contains(column) {
+        return Err(VerifyError::MissingField {
+            field: column.to_string(),
+        });
+    }
+    Ok(())
 }
 
 /// Attempts to verify the given Select Statement
-pub fn verify_select<T, F, C>(
-    select: Select<T, F, C>,
+pub fn verify_select<T, F, C, H>(
+    select: Select<T, F, C, H>,
     base_definition: &RootTableDefinitions,
 ) -> Result<(), VerifyError>
 where
     T: VerifyTable,
     F: Fields,
-    C: Condition,
+    C: VerifyCondition,
+    H: VerifyCondition,
 {
     select.get_fields(base_definition).map(|_| ())
 }
@@ -0,0 +1,308 @@
+use crate::{
+    fmt::{self, ExpressionBuilder, FieldsBuilder, FmtBuilder, FormatError},
+    sql::Sql,
+    Aggregate, Fields, OrderBy,
+};
+
+/// The Units used by a Frame of a Window
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FrameUnits {
+    /// The Bounds are counted in Rows
+    Rows,
+    /// The Bounds are Differences of the Value of the ORDER BY Key
+    Range,
+    /// The Bounds are counted in Groups of Rows with the same ORDER BY Key
+    Groups,
+}
+
+/// A single Bound of a Frame
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FrameBound {
+    /// The Start of the Partition
+    UnboundedPreceding,
+    /// The given Number of Units before the current Row
+    Preceding(u64),
+    /// The current Row
+    CurrentRow,
+    /// The given Number of Units after the current Row
+    Following(u64),
+    /// The End of the Partition
+    UnboundedFollowing,
+}
+
+/// Determines the Set of Rows in a Partition that a Window-Function is applied to
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    units: FrameUnits,
+    start: FrameBound,
+    end: Option<FrameBound>,
+}
+
+impl Frame {
+    /// Creates a new Frame from the Start Bound up to the current Row
+    pub fn new(units: FrameUnits, start: FrameBound) -> Self {
+        Self {
+            units,
+            start,
+            end: None,
+        }
+    }
+
+    /// Creates a new Frame between the two Bounds
+    pub fn between(units: FrameUnits, start: FrameBound, end: FrameBound) -> Self {
+        Self {
+            units,
+            start,
+            end: Some(end),
+        }
+    }
+
+    /// The Units of the Frame
+    pub fn units(&self) -> FrameUnits {
+        self.units
+    }
+
+    /// The Start Bound of the Frame
+    pub fn start(&self) -> FrameBound {
+        self.start
+    }
+
+    /// The End Bound of the Frame, if it is not just the current Row
+    pub fn end(&self) -> Option<FrameBound> {
+        self.end
+    }
+}
+
+/// The Definition of a Window, which can either be used directly by a Window-Function or be
+/// named as part of a Select
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Window {
+    base: Option<String>,
+    partition_by: Vec<String>,
+    order_by: Vec<OrderBy>,
+    frame: Option<Frame>,
+}
+
+impl Window {
+    /// Creates a new empty Window, which contains all the Rows
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a new Window based on the named Window of the Select
+    pub fn named<N>(name: N) -> Self
+    where
+        N: Into<String>,
+    {
+        Self {
+            base: Some(name.into()),
+            ..Self::default()
+        }
+    }
+
+    /// Adds a new Column to the PARTITION BY Clause
+    pub fn partition_by<N>(mut self, column: N) -> Self
+    where
+        N: Into<String>,
+    {
+        self.partition_by.push(column.into());
+        self
+    }
+
+    /// Adds a new Key to the ORDER BY Clause of the Window
+    pub fn order_by(mut self, order: OrderBy) -> Self {
+        self.order_by.push(order);
+        self
+    }
+
+    /// Sets the Frame of the Window
+    pub fn frame(mut self, frame: Frame) -> Self {
+        self.frame = Some(frame);
+        self
+    }
+
+    /// The Name of the Window this one is based on
+    pub fn base(&self) -> Option<&str> {
+        self.base.as_deref()
+    }
+
+    /// The Columns used for partitioning the Rows
+    pub fn partitions(&self) -> &[String] {
+        &self.partition_by
+    }
+
+    /// The Keys used for ordering the Rows in a Partition
+    pub fn ordering(&self) -> &[OrderBy] {
+        &self.order_by
+    }
+
+    /// The configured Frame, if any
+    pub fn frame_spec(&self) -> Option<&Frame> {
+        self.frame.as_ref()
+    }
+
+    /// The Columns referenced by the Window
+    pub(crate) fn columns(&self) -> impl Iterator<Item = String> + '_ {
+        self.partition_by
+            .iter()
+            .cloned()
+            .chain(self.order_by.iter().flat_map(|o| o.columns()))
+    }
+}
+
+/// The Functions that can be applied over a Window
+#[derive(Debug, Clone, PartialEq)]
+pub enum WindowFunction {
+    /// The Number of the Row in its Partition, starting at 1
+    RowNumber,
+    /// The Rank of the Row in its Partition, with Gaps after Ties
+    Rank,
+    /// The Rank of the Row in its Partition, without Gaps after Ties
+    DenseRank,
+    /// The Value of the Column in the Row the given Number of Rows before the current one
+    Lag {
+        /// The Column to load
+        column: String,
+        /// The Number of Rows to go back
+        offset: u64,
+    },
+    /// The Value of the Column in the Row the given Number of Rows after the current one
+    Lead {
+        /// The Column to load
+        column: String,
+        /// The Number of Rows to go forward
+        offset: u64,
+    },
+    /// Any Aggregate-Function, which is then applied to the Rows in the Frame
+    Aggregate(Aggregate),
+}
+
+/// A Call to a Window-Function, which can be selected like any other Field
+#[derive(Debug, Clone, PartialEq)]
+pub struct Over {
+    function: WindowFunction,
+    window: Window,
+    alias: Option<String>,
+}
+
+impl Over {
+    /// Creates a new Call of the Function over the Window
+    pub fn new(function: WindowFunction, window: Window) -> Self {
+        Self {
+            function,
+            window,
+            alias: None,
+        }
+    }
+
+    /// `ROW_NUMBER() OVER (...)`
+    pub fn row_number(window: Window) -> Self {
+        Self::new(WindowFunction::RowNumber, window)
+    }
+
+    /// `RANK() OVER (...)`
+    pub fn rank(window: Window) -> Self {
+        Self::new(WindowFunction::Rank, window)
+    }
+
+    /// `DENSE_RANK() OVER (...)`
+    pub fn dense_rank(window: Window) -> Self {
+        Self::new(WindowFunction::DenseRank, window)
+    }
+
+    /// `LAG(column, offset) OVER (...)`
+    pub fn lag<N>(column: N, offset: u64, window: Window) -> Self
+    where
+        N: Into<String>,
+    {
+        Self::new(
+            WindowFunction::Lag {
+                column: column.into(),
+                offset,
+            },
+            window,
+        )
+    }
+
+    /// `LEAD(column, offset) OVER (...)`
+    pub fn lead<N>(column: N, offset: u64, window: Window) -> Self
+    where
+        N: Into<String>,
+    {
+        Self::new(
+            WindowFunction::Lead {
+                column: column.into(),
+                offset,
+            },
+            window,
+        )
+    }
+
+    /// Sets the Name of the resulting Field
+    pub fn alias<N>(mut self, alias: N) -> Self
+    where
+        N: Into<String>,
+    {
+        self.alias = Some(alias.into());
+        self
+    }
+
+    /// The Name of the resulting Field, which is either the configured Alias or the Name of the
+    /// Function itself
+    pub fn name(&self) -> String {
+        if let Some(alias) = self.alias.as_ref() {
+            return alias.clone();
+        }
+
+        match &self.function {
+            WindowFunction::RowNumber => "row_number()".to_string(),
+            WindowFunction::Rank => "rank()".to_string(),
+            WindowFunction::DenseRank => "dense_rank()".to_string(),
+            WindowFunction::Lag { column, offset } => format!("lag({}, {})", column, offset),
+            WindowFunction::Lead { column, offset } => format!("lead({}, {})", column, offset),
+            WindowFunction::Aggregate(aggregate) => aggregate.name(),
+        }
+    }
+}
+
+impl Aggregate {
+    /// Applies the Aggregate-Function over the Window instead of the grouped Rows
+    pub fn over(self, window: Window) -> Over {
+        Over::new(WindowFunction::Aggregate(self), window)
+    }
+}
+
+impl Fields for Over {
+    type FieldIter = std::iter::Once<String>;
+
+    fn to_iterator(&self) -> Self::FieldIter {
+        std::iter::once(self.name())
+    }
+
+    fn columns(&self) -> Vec<String> {
+        let arguments = match &self.function {
+            WindowFunction::Lag { column, .. } | WindowFunction::Lead { column, .. } => {
+                vec![column.clone()]
+            }
+            WindowFunction::Aggregate(aggregate) => aggregate.columns(),
+            _ => Vec::new(),
+        };
+
+        arguments.into_iter().chain(self.window.columns()).collect()
+    }
+
+    fn format<F>(&self, fmt: &mut F) -> Result<Sql, FormatError>
+    where
+        F: fmt::Formatter,
+    {
+        let call = fmt.expression().window_function(&self.function)?;
+        let over = fmt.expression().over(call, &self.window)?;
+
+        let mut fields = fmt.fields();
+        match self.alias.as_ref() {
+            Some(alias) => fields.add_field(format!("{} AS {}", over, alias)),
+            None => fields.add_field(over.into()),
+        };
+        fields.finish()
+    }
+}
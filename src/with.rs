@@ -0,0 +1,245 @@
+use std::collections::HashSet;
+
+use crate::{
+    fmt::{FmtBuilder, FormatError, WithBuilder},
+    sql::Sql,
+    verify::{RootTableDefinitions, Scope, VerifyError, VerifyTable},
+    Statement, Table,
+};
+
+/// A single named Query of a WITH Clause, which can then be referred to by its Name like any other
+/// Table
+pub struct Cte<Q>
+where
+    Q: Statement,
+{
+    name: String,
+    columns: Vec<String>,
+    query: Q,
+}
+
+impl<Q> Cte<Q>
+where
+    Q: Statement,
+{
+    /// Creates a new Common Table Expression with the given Name
+    pub fn new<N>(name: N, query: Q) -> Self
+    where
+        N: Into<String>,
+    {
+        Self {
+            name: name.into(),
+            columns: Vec::new(),
+            query,
+        }
+    }
+
+    /// Explicitly names the Columns returned by the Query
+    pub fn columns<I, N>(mut self, columns: I) -> Self
+    where
+        I: IntoIterator<Item = N>,
+        N: Into<String>,
+    {
+        self.columns = columns.into_iter().map(|c| c.into()).collect();
+        self
+    }
+}
+
+/// Generalises over one or multiple Common Table Expressions
+pub trait CommonTableExpressions {
+    /// Adds all the Expressions to the Builder
+    fn add_to<B>(&self, builder: &mut B)
+    where
+        B: WithBuilder;
+
+    /// Adds the Fields of all the Expressions as Tables to the Definitions, so that they can be used
+    /// by the following Queries
+    fn register(
+        &self,
+        roots: &mut RootTableDefinitions,
+        recursive: bool,
+    ) -> Result<(), VerifyError>;
+}
+
+impl<Q> CommonTableExpressions for Cte<Q>
+where
+    Q: Statement + VerifyTable,
+{
+    fn add_to<B>(&self, builder: &mut B)
+    where
+        B: WithBuilder,
+    {
+        builder.cte(&self.name, &self.columns, &self.query);
+    }
+
+    fn register(
+        &self,
+        roots: &mut RootTableDefinitions,
+        recursive: bool,
+    ) -> Result<(), VerifyError> {
+        let rename = |columns: Vec<String>| {
+            if self.columns.is_empty() {
+                return Ok(columns.into_iter().collect::<HashSet<_>>());
+            }
+            if self.columns.len() != columns.len() {
+                return Err(VerifyError::ColumnCountMismatch {
+                    expected: self.columns.len(),
+                    found: columns.len(),
+                });
+            }
+
+            let mut fields = HashSet::with_capacity(self.columns.len());
+            for column in self.columns.iter() {
+                if !fields.insert(column.clone()) {
+                    return Err(VerifyError::DuplicateColumn {
+                        column: column.clone(),
+                    });
+                }
+            }
+            Ok(fields)
+        };
+
+        // A recursive Expression refers to itself, so it first needs to be registered using the
+        // Columns of its non-recursive Part, before the entire Query can be verified
+        if recursive {
+            let anchor = rename(self.query.get_anchor_columns(roots)?)?;
+            roots.add_table(self.name.clone(), anchor);
+        }
+
+        let fields = rename(self.query.get_columns(roots)?)?;
+        roots.add_table(self.name.clone(), fields);
+
+        Ok(())
+    }
+}
+
+macro_rules! tuple_ctes {
+    ($($name:ident),+) => {
+        #[allow(non_snake_case)]
+        impl<$($name),+> CommonTableExpressions for ($($name,)+)
+        where
+            $($name: CommonTableExpressions),+
+        {
+            fn add_to<B>(&self, builder: &mut B)
+            where
+                B: WithBuilder,
+            {
+                let ($($name,)+) = self;
+                $($name.add_to(builder);)+
+            }
+
+            fn register(
+                &self,
+                roots: &mut RootTableDefinitions,
+                recursive: bool,
+            ) -> Result<(), VerifyError> {
+                let ($($name,)+) = self;
+                $($name.register(roots, recursive)?;)+
+                Ok(())
+            }
+        }
+    };
+}
+
+tuple_ctes!(C1, C2);
+tuple_ctes!(C1, C2, C3);
+tuple_ctes!(C1, C2, C3, C4);
+
+/// A Statement preceded by a WITH Clause, which binds the Common Table Expressions for use in the
+/// Statement
+pub struct With<C, S>
+where
+    C: CommonTableExpressions,
+    S: Statement,
+{
+    recursive: bool,
+    ctes: C,
+    statement: S,
+}
+
+impl<C, S> With<C, S>
+where
+    C: CommonTableExpressions,
+    S: Statement,
+{
+    /// Creates a new WITH Clause for the Statement
+    pub fn new(ctes: C, statement: S) -> Self {
+        Self {
+            recursive: false,
+            ctes,
+            statement,
+        }
+    }
+
+    /// Creates a new WITH RECURSIVE Clause for the Statement, which allows the Expressions to refer
+    /// to themselves
+    pub fn recursive(ctes: C, statement: S) -> Self {
+        Self {
+            recursive: true,
+            ctes,
+            statement,
+        }
+    }
+}
+
+impl<C, S> Statement for With<C, S>
+where
+    C: CommonTableExpressions,
+    S: Statement,
+{
+    fn format<F>(&self, fmt: &mut F) -> Result<Sql, FormatError>
+    where
+        F: crate::fmt::Formatter,
+    {
+        let mut builder = fmt.with();
+        builder.recursive(self.recursive);
+        self.ctes.add_to(&mut builder);
+        builder.statement(&self.statement).finish()
+    }
+}
+impl<C, S> Table for With<C, S>
+where
+    C: CommonTableExpressions,
+    S: Statement,
+{
+    fn format<F>(&self, fmt: &mut F) -> Result<Sql, FormatError>
+    where
+        F: crate::fmt::Formatter,
+    {
+        let inner = Statement::format(self, fmt)?;
+        Ok(Sql::new(format!("({})", inner)))
+    }
+}
+
+impl<C, S> VerifyTable for With<C, S>
+where
+    C: CommonTableExpressions,
+    S: Statement + VerifyTable,
+{
+    fn get_fields(&self, roots: &RootTableDefinitions) -> Result<HashSet<String>, VerifyError> {
+        self.get_fields_scoped(roots, None)
+    }
+
+    fn get_fields_scoped(
+        &self,
+        roots: &RootTableDefinitions,
+        outer: Option<&Scope<'_>>,
+    ) -> Result<HashSet<String>, VerifyError> {
+        // The Expressions are only visible inside of this Statement
+        let mut scoped = roots.clone();
+        self.ctes.register(&mut scoped, self.recursive)?;
+
+        self.statement.get_fields_scoped(&scoped, outer)
+    }
+
+    fn get_columns_scoped(
+        &self,
+        roots: &RootTableDefinitions,
+        outer: Option<&Scope<'_>>,
+    ) -> Result<Vec<String>, VerifyError> {
+        let mut scoped = roots.clone();
+        self.ctes.register(&mut scoped, self.recursive)?;
+
+        self.statement.get_columns_scoped(&scoped, outer)
+    }
+}
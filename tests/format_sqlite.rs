@@ -1,4 +1,13 @@
-use sqlf::Select;
+use sqlf::{
+    fmt::{ExpressionBuilder, FormatError, Formatter, Limits},
+    sql::Types,
+    Aggregate, AllFields, AlterTable, BatchInsert, Begin, Case, Collation, Column, Commit,
+    CompareSelect, Comparison, Condition, Constraint, CreateFtsTable, CreateIndex, CreateTable,
+    CreateView, Cte, DatePart, DateTimeUnit, Delete, DropStatement, Exists, Expression, Frame,
+    FrameBound, FrameUnits, FtsCall, FtsMatch, Generated, InSelect, Insert, InsertSelect, Interval,
+    IsolationLevel, Join, JsonEach, JsonPath, Locking, OnConflict, OrderBy, Over, Release,
+    Rollback, Savepoint, Script, Select, Table, Update, Window, WindowFunction, With,
+};
 
 #[test]
 fn select() {
@@ -6,7 +15,7 @@ fn select() {
 
     let select = Select::new("test", (), &["test"]);
 
-    let result = formatter.format(&select);
+    let result = formatter.format(&select).unwrap();
     dbg!(result);
 }
 
@@ -20,7 +29,7 @@ fn nested_select() {
         &["test"],
     );
 
-    let result = formatter.format(&select);
+    let result = formatter.format(&select).unwrap();
     dbg!(result);
 }
 
@@ -30,6 +39,1224 @@ fn conditional_select() {
 
     let select = Select::new("test", ("test", "value"), &["test"]);
 
-    let result = formatter.format(&select);
+    let result = formatter.format(&select).unwrap();
     dbg!(result);
 }
+
+#[test]
+fn joined_select() {
+    let mut formatter = sqlf::fmt::sqlite::SqliteFormatter::new();
+
+    let select = Select::new(
+        "users"
+            .alias("u")
+            .left_join("orders".alias("o"), ("u.id", "o.user_id")),
+        (),
+        &["u.name", "o.id"],
+    );
+
+    let result = formatter.format(&select).unwrap();
+    assert_eq!(
+        "SELECT u.name,o.id FROM users AS u LEFT JOIN orders AS o ON u.id=o.user_id",
+        result.to_string()
+    );
+
+    let select = Select::new(
+        "users".left_join(
+            "orders".inner_join("items", ("orders.id", "items.order_id")),
+            ("users.id", "orders.user_id"),
+        ),
+        (),
+        &["users.name", "items.name"],
+    );
+
+    let result = formatter.format(&select).unwrap();
+    assert_eq!(
+        "SELECT users.name,items.name FROM users LEFT JOIN (orders INNER JOIN items ON orders.id=items.order_id) ON users.id=orders.user_id",
+        result.to_string()
+    );
+
+    let select = Select::new(
+        "users"
+            .inner_join("orders", ("users.id", "orders.user_id"))
+            .alias("z"),
+        (),
+        &["z.name"],
+    );
+
+    let result = formatter.format(&select).unwrap();
+    assert_eq!(
+        "SELECT z.name FROM (users INNER JOIN orders ON users.id=orders.user_id) AS z",
+        result.to_string()
+    );
+}
+
+#[test]
+fn cross_joined_subselect() {
+    let mut formatter = sqlf::fmt::sqlite::SqliteFormatter::new();
+
+    let select = Select::new(
+        Join::cross(Select::new("inner", (), &["test"]).alias("i"), "other"),
+        (),
+        AllFields {},
+    );
+
+    let result = formatter.format(&select).unwrap();
+    assert_eq!(
+        "SELECT * FROM (SELECT test FROM inner) AS i CROSS JOIN other",
+        result.to_string()
+    );
+}
+
+#[test]
+fn ordered_select() {
+    let mut formatter = sqlf::fmt::sqlite::SqliteFormatter::new();
+
+    let select = Select::new("test", (), &["first", "second"])
+        .order_by(OrderBy::desc("first").nulls_last())
+        .order_by(OrderBy::asc("second"))
+        .limit(10)
+        .offset(20);
+
+    let result = formatter.format(&select).unwrap();
+    assert_eq!(
+        "SELECT first,second FROM test ORDER BY first DESC NULLS LAST,second ASC LIMIT 10 OFFSET 20",
+        result.to_string()
+    );
+}
+
+#[test]
+fn offset_only_select() {
+    let mut formatter = sqlf::fmt::sqlite::SqliteFormatter::new();
+
+    let select = Select::new("test", (), &["first"]).offset(5);
+
+    let result = formatter.format(&select).unwrap();
+    assert_eq!(
+        "SELECT first FROM test LIMIT -1 OFFSET 5",
+        result.to_string()
+    );
+}
+
+#[test]
+fn grouped_select() {
+    let mut formatter = sqlf::fmt::sqlite::SqliteFormatter::new();
+
+    let select = Select::new(
+        "orders",
+        (),
+        (
+            &["user_id"],
+            Aggregate::count_all().alias("total"),
+            Aggregate::group_concat("item", ", "),
+        ),
+    )
+    .group_by("user_id")
+    .having(Aggregate::count_all().compare(Comparison::Equal, 2));
+
+    let result = formatter.format(&select).unwrap();
+    assert_eq!(
+        "SELECT user_id,COUNT(*) AS total,GROUP_CONCAT(item, ', ') FROM orders GROUP BY user_id HAVING COUNT(*)=2",
+        result.to_string()
+    );
+
+    let select = Select::new(
+        "orders",
+        (),
+        (
+            &["user_id"],
+            Aggregate::sum(Expression::column("price").multiply("quantity")).alias("revenue"),
+        ),
+    )
+    .group_by("user_id")
+    .having(
+        Aggregate::count_all()
+            .compare(Comparison::Greater, 5)
+            .and(Aggregate::sum("price").compare(Comparison::GreaterEqual, 100)),
+    );
+
+    let result = formatter.format(&select).unwrap();
+    assert_eq!(
+        "SELECT user_id,SUM(price*quantity) AS revenue FROM orders GROUP BY user_id HAVING (COUNT(*)>5) AND (SUM(price)>=100)",
+        result.to_string()
+    );
+
+    let select = Select::new(
+        "orders",
+        (),
+        (
+            &["user_id"],
+            Aggregate::group_concat("item", ", ").distinct(),
+        ),
+    )
+    .group_by("user_id");
+
+    let result = formatter.format(&select);
+    assert_eq!(
+        Err(FormatError::Unsupported {
+            feature: "GROUP_CONCAT(DISTINCT ...) with a Separator".to_string()
+        }),
+        result.map(String::from)
+    );
+}
+
+#[test]
+fn distinct_select() {
+    let mut formatter = sqlf::fmt::sqlite::SqliteFormatter::new();
+
+    let select = Select::new("test", (), &["first"]).distinct();
+
+    let result = formatter.format(&select).unwrap();
+    assert_eq!("SELECT DISTINCT first FROM test", result.to_string());
+}
+
+#[test]
+fn distinct_on_select() {
+    let mut formatter = sqlf::fmt::sqlite::SqliteFormatter::new();
+
+    let select = Select::new("test", (), &["first", "second"])
+        .distinct_on(["first"])
+        .order_by(OrderBy::asc("first"));
+
+    let result = formatter.format(&select);
+    assert_eq!(
+        Err(FormatError::Unsupported {
+            feature: "DISTINCT ON".to_string()
+        }),
+        result.map(String::from)
+    );
+}
+
+#[test]
+fn locking_select() {
+    let mut formatter = sqlf::fmt::sqlite::SqliteFormatter::new();
+
+    let select = Select::new("jobs", (), &["id", "payload"])
+        .order_by(OrderBy::asc("id"))
+        .limit(1)
+        .lock(Locking::update().skip_locked());
+
+    let result = formatter.format(&select);
+    assert_eq!(
+        Err(FormatError::Unsupported {
+            feature: "FOR UPDATE SKIP LOCKED".to_string()
+        }),
+        result.map(String::from)
+    );
+
+    let select = Select::new("jobs", (), &["id"]).lock(Locking::share().of("jobs").nowait());
+
+    let result = formatter.format(&select);
+    assert_eq!(
+        Err(FormatError::Unsupported {
+            feature: "FOR SHARE OF jobs NOWAIT".to_string()
+        }),
+        result.map(String::from)
+    );
+}
+
+#[test]
+fn compound_select() {
+    let mut formatter = sqlf::fmt::sqlite::SqliteFormatter::new();
+
+    let select = Select::new("first", (), &["name"])
+        .union_all(Select::new("second", (), &["name"]))
+        .except(Select::new("third", (), &["name"]));
+
+    let result = formatter.format(&select).unwrap();
+    assert_eq!(
+        "SELECT name FROM first UNION ALL SELECT name FROM second EXCEPT SELECT name FROM third",
+        result.to_string()
+    );
+
+    let nested = Select::new(
+        Select::new("first", (), &["name"]).union(Select::new("second", (), &["name"])),
+        (),
+        AllFields {},
+    );
+
+    let result = formatter.format(&nested).unwrap();
+    assert_eq!(
+        "SELECT * FROM (SELECT name FROM first UNION SELECT name FROM second)",
+        result.to_string()
+    );
+
+    let select = Select::new("first", (), &["name"])
+        .except(Select::new("second", (), &["name"]).union(Select::new("third", (), &["name"])));
+
+    let result = formatter.format(&select);
+    assert_eq!(
+        Err(FormatError::Unsupported {
+            feature: "Compounds or WITH on the right Side of a Compound".to_string()
+        }),
+        result.map(String::from)
+    );
+
+    let select = Select::new("first", (), &["name"])
+        .order_by(OrderBy::asc("name"))
+        .limit(1)
+        .union(Select::new("second", (), &["name"]));
+
+    let result = formatter.format(&select);
+    assert_eq!(
+        Err(FormatError::Unsupported {
+            feature: "ORDER BY, LIMIT or OFFSET in a Part of a Compound".to_string()
+        }),
+        result.map(String::from)
+    );
+}
+
+#[test]
+fn recursive_with_select() {
+    let mut formatter = sqlf::fmt::sqlite::SqliteFormatter::new();
+
+    let tree = Select::new("categories", ("id", "1"), &["id", "parent"]).union_all(Select::new(
+        "categories"
+            .alias("c")
+            .inner_join("tree", ("c.parent", "tree.id")),
+        (),
+        &["c.id", "c.parent"],
+    ));
+    let query = With::recursive(
+        Cte::new("tree", tree).columns(["id", "parent"]),
+        Select::new("tree", (), AllFields {}),
+    );
+
+    let result = formatter.format(&query).unwrap();
+    assert_eq!(
+        "WITH RECURSIVE tree(id,parent) AS (SELECT id,parent FROM categories WHERE id=1 UNION ALL SELECT c.id,c.parent FROM categories AS c INNER JOIN tree ON c.parent=tree.id) SELECT * FROM tree",
+        result.to_string()
+    );
+}
+
+#[test]
+fn subquery_conditions() {
+    let mut formatter = sqlf::fmt::sqlite::SqliteFormatter::new();
+
+    let select = Select::new(
+        "users",
+        InSelect::new("id", Select::new("orders", (), &["user_id"])).and(Exists::negated(
+            Select::new("bans", ("bans.user_id", "users.id"), AllFields {}),
+        )),
+        &["name"],
+    );
+
+    let result = formatter.format(&select).unwrap();
+    assert_eq!(
+        "SELECT name FROM users WHERE (id IN (SELECT user_id FROM orders)) AND (NOT EXISTS (SELECT * FROM bans WHERE bans.user_id=users.id))",
+        result.to_string()
+    );
+
+    let select = Select::new(
+        "orders",
+        CompareSelect::new(
+            "total",
+            Comparison::Greater,
+            Select::new("orders", (), Aggregate::avg("total")),
+        ),
+        &["id"],
+    );
+
+    let result = formatter.format(&select).unwrap();
+    assert_eq!(
+        "SELECT id FROM orders WHERE total>(SELECT AVG(total) FROM orders)",
+        result.to_string()
+    );
+}
+
+#[test]
+fn window_functions() {
+    let mut formatter = sqlf::fmt::sqlite::SqliteFormatter::new();
+
+    let select = Select::new(
+        "employees",
+        (),
+        (
+            "name",
+            Over::row_number(
+                Window::new()
+                    .partition_by("department")
+                    .order_by(OrderBy::desc("salary")),
+            )
+            .alias("position"),
+        ),
+    );
+
+    let result = formatter.format(&select).unwrap();
+    assert_eq!(
+        "SELECT name,ROW_NUMBER() OVER (PARTITION BY department ORDER BY salary DESC) AS position FROM employees",
+        result.to_string()
+    );
+
+    let select = Select::new(
+        "sales",
+        (),
+        (
+            "day",
+            Aggregate::sum("amount")
+                .over(Window::named("w").frame(Frame::between(
+                    FrameUnits::Rows,
+                    FrameBound::Preceding(2),
+                    FrameBound::CurrentRow,
+                )))
+                .alias("rolling"),
+            Over::lag("amount", 1, Window::named("w")).alias("previous"),
+        ),
+    )
+    .window("w", Window::new().order_by(OrderBy::asc("day")));
+
+    let result = formatter.format(&select).unwrap();
+    assert_eq!(
+        "SELECT day,SUM(amount) OVER (w ROWS BETWEEN 2 PRECEDING AND CURRENT ROW) AS rolling,LAG(amount, 1) OVER w AS previous FROM sales WINDOW w AS (ORDER BY day ASC)",
+        result.to_string()
+    );
+
+    let result = formatter
+        .expression()
+        .window_function(&WindowFunction::Aggregate(Aggregate::sum("amount")))
+        .unwrap();
+    assert_eq!("SUM(amount)", result.to_string());
+}
+
+#[test]
+fn expressions() {
+    let mut formatter = sqlf::fmt::sqlite::SqliteFormatter::new();
+
+    let select = Select::new(
+        "orders",
+        Expression::column("price")
+            .multiply(Expression::column("quantity").minus(1))
+            .compare(Comparison::Greater, 100)
+            .and(Expression::column("note").is_not_null()),
+        (
+            Expression::column("first_name")
+                .concat(Expression::text(" "))
+                .concat("last_name")
+                .alias("full_name"),
+            Case::new()
+                .when(
+                    Expression::column("quantity").compare(Comparison::Greater, 10),
+                    Expression::text("bulk"),
+                )
+                .otherwise(Expression::text("single"))
+                .alias("kind"),
+            Expression::column("total")
+                .cast(Types::Integer)
+                .alias("rounded"),
+            Expression::coalesce(["discount", "rebate"]).alias("reduction"),
+            Expression::null_if("status", Expression::text("")).alias("status"),
+        ),
+    )
+    .order_by(OrderBy::desc(
+        Expression::column("price").multiply("quantity"),
+    ));
+
+    let result = formatter.format(&select).unwrap();
+    assert_eq!(
+        "SELECT (first_name||' ')||last_name AS full_name,CASE WHEN quantity>10 THEN 'bulk' ELSE 'single' END AS kind,CAST(total AS INTEGER) AS rounded,COALESCE(discount, rebate) AS reduction,NULLIF(status, '') AS status FROM orders WHERE ((price*(quantity-1))>100) AND (note IS NOT NULL) ORDER BY price*quantity DESC",
+        result.to_string()
+    );
+}
+
+#[test]
+fn negative_literals() {
+    let mut formatter = sqlf::fmt::sqlite::SqliteFormatter::new();
+
+    let select = Select::new(
+        "t",
+        (),
+        (
+            "a",
+            Expression::column("x")
+                .minus(Expression::value(-1i64))
+                .alias("y"),
+        ),
+    );
+
+    let result = formatter.format(&select).unwrap();
+    assert_eq!("SELECT a,x-(-1) AS y FROM t", result.to_string());
+}
+
+#[test]
+fn insert() {
+    let mut formatter = sqlf::fmt::sqlite::SqliteFormatter::new();
+
+    let insert = Insert::new("users")
+        .value("id", Expression::value(1))
+        .value("name", Expression::text("O'Brien"));
+
+    let result = formatter.format(&insert).unwrap();
+    assert_eq!(
+        "INSERT INTO users (id,name) VALUES (1,'O''Brien')",
+        result.to_string()
+    );
+
+    let insert = Insert::new("users");
+
+    let result = formatter.format(&insert).unwrap();
+    assert_eq!("INSERT INTO users DEFAULT VALUES", result.to_string());
+}
+
+#[test]
+fn upsert() {
+    let mut formatter = sqlf::fmt::sqlite::SqliteFormatter::new();
+
+    let insert = Insert::new("users")
+        .value("id", Expression::value(1))
+        .value("name", Expression::text("test"))
+        .on_conflict(OnConflict::any());
+
+    let result = formatter.format(&insert).unwrap();
+    assert_eq!(
+        "INSERT INTO users (id,name) VALUES (1,'test') ON CONFLICT DO NOTHING",
+        result.to_string()
+    );
+
+    let insert = Insert::new("users").on_conflict(OnConflict::any());
+    let result = formatter.format(&insert);
+    assert_eq!(
+        Err(FormatError::Unsupported {
+            feature: "ON CONFLICT with DEFAULT VALUES".to_string()
+        }),
+        result.map(String::from)
+    );
+
+    let insert = Insert::new("users")
+        .value("id", Expression::value(1))
+        .value("name", Expression::text("test"))
+        .value("version", Expression::value(2))
+        .on_conflict(
+            OnConflict::columns(["id"])
+                .set_excluded(["name", "version"])
+                .condition(
+                    Expression::excluded("version").compare(Comparison::Greater, "users.version"),
+                ),
+        );
+
+    let result = formatter.format(&insert).unwrap();
+    assert_eq!(
+        "INSERT INTO users (id,name,version) VALUES (1,'test',2) ON CONFLICT (id) DO UPDATE SET name=excluded.name,version=excluded.version WHERE excluded.version>users.version",
+        result.to_string()
+    );
+
+    let insert = Insert::new("users")
+        .value("id", Expression::value(1))
+        .on_conflict(OnConflict::constraint("users_pkey"));
+
+    let result = formatter.format(&insert);
+    assert_eq!(
+        Err(FormatError::Unsupported {
+            feature: "ON CONFLICT ON CONSTRAINT".to_string()
+        }),
+        result.map(String::from)
+    );
+}
+
+#[test]
+fn returning() {
+    let mut formatter = sqlf::fmt::sqlite::SqliteFormatter::new();
+
+    let insert = Insert::new("users")
+        .value("name", Expression::text("test"))
+        .returning(&["id", "name"]);
+
+    let result = formatter.format(&insert).unwrap();
+    assert_eq!(
+        "INSERT INTO users (name) VALUES ('test') RETURNING id,name",
+        result.to_string()
+    );
+
+    let update = Update::new("users", ("id", "1"))
+        .set("visits", Expression::column("visits").plus(1))
+        .set("name", Expression::text("other"))
+        .returning(Expression::column("visits").alias("total"));
+
+    let result = formatter.format(&update).unwrap();
+    assert_eq!(
+        "UPDATE users SET visits=visits+1,name='other' WHERE id=1 RETURNING visits AS total",
+        result.to_string()
+    );
+
+    let delete = Delete::new("users", Expression::column("name").is_null());
+
+    let result = formatter.format(&delete).unwrap();
+    assert_eq!("DELETE FROM users WHERE name IS NULL", result.to_string());
+
+    let delete = Delete::new("users", ()).returning(AllFields {});
+
+    let result = formatter.format(&delete).unwrap();
+    assert_eq!("DELETE FROM users RETURNING *", result.to_string());
+}
+
+#[test]
+fn insert_select() {
+    let mut formatter = sqlf::fmt::sqlite::SqliteFormatter::new();
+
+    let insert = InsertSelect::new(
+        "archive",
+        ["id", "name"],
+        Select::new("users", ("active", "0"), &["id", "name"]),
+    );
+
+    let result = formatter.format(&insert).unwrap();
+    assert_eq!(
+        "INSERT INTO archive (id,name) SELECT * FROM (SELECT id,name FROM users WHERE active=0)",
+        result.to_string()
+    );
+
+    let insert = InsertSelect::new("archive", Vec::<String>::new(), "staging")
+        .on_conflict(OnConflict::columns(["id"]).set_excluded(["name"]));
+
+    let result = formatter.format(&insert).unwrap();
+    assert_eq!(
+        "INSERT INTO archive SELECT * FROM staging WHERE true ON CONFLICT (id) DO UPDATE SET name=excluded.name",
+        result.to_string()
+    );
+}
+
+#[test]
+fn batch_insert() {
+    let mut formatter = sqlf::fmt::sqlite::SqliteFormatter::with_limits(Limits {
+        parameters: 5,
+        length: 1_000,
+    });
+
+    let rows = (0..5).map(|i| vec![Expression::value(i), Expression::parameter()]);
+    let insert = BatchInsert::new("users", ["id", "name"], rows);
+
+    let result = insert
+        .format(&mut formatter)
+        .map(|s| s.map(String::from))
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    assert_eq!(
+        vec!["INSERT INTO users (id,name) VALUES (0,?),(1,?),(2,?),(3,?),(4,?)".to_string(),],
+        result
+    );
+
+    let rows = (0..5).map(|_| vec![Expression::parameter(), Expression::parameter()]);
+    let insert = BatchInsert::new("users", ["id", "name"], rows)
+        .on_conflict(OnConflict::columns(["id"]).set("name", Expression::parameter()));
+
+    let result = insert
+        .format(&mut formatter)
+        .map(|s| s.map(String::from))
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    assert_eq!(
+        vec![
+            "INSERT INTO users (id,name) VALUES (?,?),(?,?) ON CONFLICT (id) DO UPDATE SET name=?"
+                .to_string(),
+            "INSERT INTO users (id,name) VALUES (?,?),(?,?) ON CONFLICT (id) DO UPDATE SET name=?"
+                .to_string(),
+            "INSERT INTO users (id,name) VALUES (?,?) ON CONFLICT (id) DO UPDATE SET name=?"
+                .to_string(),
+        ],
+        result
+    );
+
+    let mut formatter = sqlf::fmt::sqlite::SqliteFormatter::with_limits(Limits {
+        parameters: 999,
+        length: 60,
+    });
+
+    let rows = ["first", "second", "third"]
+        .iter()
+        .map(|name| vec![Expression::text(*name)]);
+    let result = BatchInsert::new("users", ["name"], rows)
+        .format(&mut formatter)
+        .map(|s| s.map(String::from))
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    assert_eq!(
+        vec![
+            "INSERT INTO users (name) VALUES ('first'),('second')".to_string(),
+            "INSERT INTO users (name) VALUES ('third')".to_string(),
+        ],
+        result
+    );
+
+    let rows = vec![vec![Expression::text("a".repeat(100))]];
+    let mut result = BatchInsert::new("users", ["name"], rows).format(&mut formatter);
+    assert_eq!(
+        Some(Err(FormatError::LimitExceeded {
+            limit: "60 Bytes".to_string()
+        })),
+        result.next().map(|s| s.map(String::from))
+    );
+    assert!(result.next().is_none());
+
+    let rows = vec![vec![Expression::value(1)]];
+    let mut result = BatchInsert::new("users", ["id", "name"], rows).format(&mut formatter);
+    assert_eq!(
+        Some(Err(FormatError::RowLength {
+            expected: 2,
+            found: 1
+        })),
+        result.next().map(|s| s.map(String::from))
+    );
+
+    let rows = vec![Vec::new(), Vec::new()];
+    let mut result = BatchInsert::new("users", Vec::<String>::new(), rows).format(&mut formatter);
+    assert_eq!(
+        Some(Err(FormatError::Invalid {
+            reason: "A Batch needs at least one Column".to_string()
+        })),
+        result.next().map(|s| s.map(String::from))
+    );
+    assert!(result.next().is_none());
+}
+
+#[test]
+fn drop_statements() {
+    let mut formatter = sqlf::fmt::sqlite::SqliteFormatter::new();
+
+    let result = formatter.format(&DropStatement::table("users")).unwrap();
+    assert_eq!("DROP TABLE users", result.to_string());
+
+    let result = formatter
+        .format(&DropStatement::index("users_name").if_exists())
+        .unwrap();
+    assert_eq!("DROP INDEX IF EXISTS users_name", result.to_string());
+
+    let result = formatter
+        .format(&DropStatement::view("active_users").if_exists())
+        .unwrap();
+    assert_eq!("DROP VIEW IF EXISTS active_users", result.to_string());
+
+    let result = formatter.format(&DropStatement::table("users").cascade());
+    assert_eq!(
+        Err(FormatError::Unsupported {
+            feature: "DROP TABLE ... CASCADE".to_string()
+        }),
+        result.map(String::from)
+    );
+}
+
+#[test]
+fn create_table() {
+    let mut formatter = sqlf::fmt::sqlite::SqliteFormatter::new();
+
+    let table = CreateTable::new(
+        "users",
+        vec![
+            ("id".to_string(), Types::Integer),
+            ("name".to_string(), Types::String),
+            ("age".to_string(), Types::Integer),
+            ("group_id".to_string(), Types::Integer),
+        ],
+    )
+    .constraint(Constraint::primary_key(["id"]))
+    .constraint(Constraint::unique(["name"]).named("users_name"))
+    .constraint(Constraint::foreign_key(["group_id"], "groups", ["id"]))
+    .constraint(Constraint::check(
+        Expression::column("age").compare(Comparison::GreaterEqual, Expression::value(0)),
+    ));
+
+    let result = formatter.format(&table).unwrap();
+    assert_eq!(
+        "CREATE TABLE users (id INTEGER,name TEXT,age INTEGER,group_id INTEGER,PRIMARY KEY (id),CONSTRAINT users_name UNIQUE (name),FOREIGN KEY (group_id) REFERENCES groups (id),CHECK (age>=0))",
+        result.to_string()
+    );
+}
+
+#[test]
+fn alter_table() {
+    let mut formatter = sqlf::fmt::sqlite::SqliteFormatter::new();
+
+    let alter = AlterTable::new("users")
+        .add_column("email", Types::String)
+        .rename_column("name", "username")
+        .rename_to("accounts")
+        .drop_column("age");
+    let result = formatter.format(&alter).unwrap();
+    assert_eq!(
+        "ALTER TABLE users ADD COLUMN email TEXT; ALTER TABLE users RENAME COLUMN name TO username; ALTER TABLE users RENAME TO accounts; ALTER TABLE accounts DROP COLUMN age",
+        result.to_string()
+    );
+
+    let definition = CreateTable::new(
+        "users",
+        vec![
+            ("id".to_string(), Types::Integer),
+            ("name".to_string(), Types::String),
+            ("age".to_string(), Types::String),
+        ],
+    )
+    .constraint(Constraint::unique(["name"]).named("users_name"));
+    let alter = AlterTable::new("users")
+        .alter_column_type("age", Types::Integer)
+        .drop_constraint("users_name")
+        .add_constraint(Constraint::primary_key(["id"]))
+        .rename_column("name", "username")
+        .definition(definition)
+        .foreign_keys_enabled();
+    let result = formatter.format(&alter).unwrap();
+    assert_eq!(
+        "PRAGMA foreign_keys=OFF; SAVEPOINT alter_users; CREATE TABLE new_users (id INTEGER,username TEXT,age INTEGER,PRIMARY KEY (id)); INSERT INTO new_users (id,username,age) SELECT id,name,age FROM users; DROP TABLE users; ALTER TABLE new_users RENAME TO users; PRAGMA foreign_key_check; RELEASE SAVEPOINT alter_users; PRAGMA foreign_keys=ON",
+        result.to_string()
+    );
+
+    let alter = AlterTable::new("users").add_constraint(Constraint::unique(["name"]));
+    let result = formatter.format(&alter);
+    assert_eq!(
+        Err(FormatError::Unsupported {
+            feature: "ADD CONSTRAINT without the Definition of the Table".to_string()
+        }),
+        result.map(String::from)
+    );
+
+    // Constraints follow renamed Columns and the Indexes are restored inside of the Transaction
+    let definition = CreateTable::new(
+        "users",
+        vec![
+            ("id".to_string(), Types::Integer),
+            ("name".to_string(), Types::String),
+        ],
+    )
+    .constraint(Constraint::unique(["name"]))
+    .constraint(Constraint::check(
+        Expression::column("name").compare(Comparison::NotEqual, Expression::text("")),
+    ));
+    let script = Script::new((
+        Begin::new(),
+        AlterTable::new("users")
+            .alter_column_type("id", Types::Integer)
+            .rename_column("name", "username")
+            .definition(definition.clone())
+            .restore(CreateIndex::new(
+                "users_username",
+                "users",
+                [OrderBy::asc("username")],
+            )),
+        Commit,
+    ));
+    let result = formatter.format(&script).unwrap();
+    assert_eq!(
+        "BEGIN; SAVEPOINT alter_users; CREATE TABLE new_users (id INTEGER,username TEXT,UNIQUE (username),CHECK (username<>'')); INSERT INTO new_users (id,username) SELECT id,name FROM users; DROP TABLE users; ALTER TABLE new_users RENAME TO users; CREATE INDEX users_username ON users (username ASC); PRAGMA foreign_key_check; RELEASE SAVEPOINT alter_users; COMMIT",
+        result.to_string()
+    );
+
+    let alter = AlterTable::new("users")
+        .drop_column("name")
+        .alter_column_type("id", Types::Integer)
+        .definition(definition.clone());
+    let result = formatter.format(&alter);
+    assert_eq!(
+        Err(FormatError::Invalid {
+            reason: "The Column name is still used by a Constraint".to_string()
+        }),
+        result.map(String::from)
+    );
+
+    let alter = AlterTable::new("users")
+        .alter_column_type("agee", Types::Integer)
+        .definition(definition.clone());
+    let result = formatter.format(&alter);
+    assert_eq!(
+        Err(FormatError::Invalid {
+            reason: "The Column agee does not exist".to_string()
+        }),
+        result.map(String::from)
+    );
+
+    let alter = AlterTable::new("users")
+        .drop_constraint("users_email")
+        .definition(definition);
+    let result = formatter.format(&alter);
+    assert_eq!(
+        Err(FormatError::Invalid {
+            reason: "The Constraint users_email does not exist".to_string()
+        }),
+        result.map(String::from)
+    );
+}
+
+#[test]
+fn create_index() {
+    let mut formatter = sqlf::fmt::sqlite::SqliteFormatter::new();
+
+    let index = CreateIndex::new("users_name", "users", [OrderBy::asc("name")]).unique();
+    let result = formatter.format(&index).unwrap();
+    assert_eq!(
+        "CREATE UNIQUE INDEX users_name ON users (name ASC)",
+        result.to_string()
+    );
+
+    let index = CreateIndex::new(
+        "orders_total",
+        "orders",
+        [
+            OrderBy::asc("customer"),
+            OrderBy::desc(Expression::column("price").multiply(Expression::column("quantity"))),
+        ],
+    )
+    .condition(Expression::column("cancelled").is_null());
+    let result = formatter.format(&index).unwrap();
+    assert_eq!(
+        "CREATE INDEX orders_total ON orders (customer ASC,price*quantity DESC) WHERE cancelled IS NULL",
+        result.to_string()
+    );
+
+    let index = CreateIndex::new("users_age", "users", [OrderBy::asc("age").nulls_last()]);
+    let result = formatter.format(&index);
+    assert_eq!(
+        Err(FormatError::Unsupported {
+            feature: "NULLS FIRST/LAST in an Index".to_string()
+        }),
+        result.map(String::from)
+    );
+}
+
+#[test]
+fn create_view() {
+    let mut formatter = sqlf::fmt::sqlite::SqliteFormatter::new();
+
+    let view = CreateView::new(
+        "active_users",
+        Select::new("users", ("active", "1"), &["id", "name"]),
+    );
+    let result = formatter.format(&view).unwrap();
+    assert_eq!(
+        "CREATE VIEW active_users AS SELECT id,name FROM users WHERE active=1",
+        result.to_string()
+    );
+
+    let view = CreateView::new(
+        "active_users",
+        Select::new("users", ("active", "1"), &["id", "name"]),
+    )
+    .columns(["user_id", "user_name"]);
+    let result = formatter.format(&view).unwrap();
+    assert_eq!(
+        "CREATE VIEW active_users (user_id,user_name) AS SELECT id,name FROM users WHERE active=1",
+        result.to_string()
+    );
+}
+
+#[test]
+fn transactions() {
+    let mut formatter = sqlf::fmt::sqlite::SqliteFormatter::new();
+
+    let result = formatter.format(&Begin::new()).unwrap();
+    assert_eq!("BEGIN", result.to_string());
+
+    let result = formatter.format(&Begin::immediate()).unwrap();
+    assert_eq!("BEGIN IMMEDIATE", result.to_string());
+
+    let result = formatter
+        .format(&Begin::exclusive().isolation(IsolationLevel::Serializable))
+        .unwrap();
+    assert_eq!("BEGIN EXCLUSIVE", result.to_string());
+
+    let result = formatter.format(&Begin::new().isolation(IsolationLevel::ReadCommitted));
+    assert_eq!(
+        Err(FormatError::Unsupported {
+            feature: "Isolation Levels other than SERIALIZABLE".to_string()
+        }),
+        result.map(String::from)
+    );
+
+    let result = formatter.format(&Savepoint::new("before_import")).unwrap();
+    assert_eq!("SAVEPOINT before_import", result.to_string());
+
+    let result = formatter.format(&Rollback::to("before_import")).unwrap();
+    assert_eq!("ROLLBACK TO SAVEPOINT before_import", result.to_string());
+
+    let result = formatter.format(&Release::new("before_import")).unwrap();
+    assert_eq!("RELEASE SAVEPOINT before_import", result.to_string());
+
+    let result = formatter.format(&Rollback::new()).unwrap();
+    assert_eq!("ROLLBACK", result.to_string());
+
+    let result = formatter.format(&Commit).unwrap();
+    assert_eq!("COMMIT", result.to_string());
+}
+
+#[test]
+fn script() {
+    let mut formatter = sqlf::fmt::sqlite::SqliteFormatter::new();
+
+    let seeds = vec![
+        Insert::new("users").value("name", Expression::text("alice")),
+        Insert::new("users").value("name", Expression::text("bob")),
+    ];
+    let script = Script::new((
+        Begin::new(),
+        CreateTable::new(
+            "users",
+            vec![
+                ("id".to_string(), Types::Integer),
+                ("name".to_string(), Types::String),
+            ],
+        ),
+        CreateIndex::new("users_name", "users", [OrderBy::asc("name")]).unique(),
+        Script::new(seeds),
+        Commit,
+    ));
+
+    let result = formatter.format(&script).unwrap();
+    assert_eq!(
+        "BEGIN; CREATE TABLE users (id INTEGER,name TEXT); CREATE UNIQUE INDEX users_name ON users (name ASC); INSERT INTO users (name) VALUES ('alice'); INSERT INTO users (name) VALUES ('bob'); COMMIT",
+        result.to_string()
+    );
+
+    let result = formatter
+        .format(&Script::new(Vec::<Commit>::new()))
+        .unwrap();
+    assert_eq!("", result.to_string());
+}
+
+#[test]
+fn table_options() {
+    let mut formatter = sqlf::fmt::sqlite::SqliteFormatter::new();
+
+    let table = CreateTable::new(
+        "readings",
+        [
+            Column::new("id", Types::Integer).autoincrement(),
+            Column::new("celsius", Types::Float),
+            Column::new("fahrenheit", Types::Float).generated(
+                Expression::column("celsius")
+                    .multiply(Expression::value(1.8))
+                    .plus(Expression::value(32)),
+                Generated::Virtual,
+            ),
+            Column::new("label", Types::String)
+                .generated(Expression::text("sensor"), Generated::Stored),
+        ],
+    )
+    .strict();
+    let result = formatter.format(&table).unwrap();
+    assert_eq!(
+        "CREATE TABLE readings (id INTEGER PRIMARY KEY AUTOINCREMENT,celsius REAL,fahrenheit REAL GENERATED ALWAYS AS ((celsius*1.8)+32) VIRTUAL,label TEXT GENERATED ALWAYS AS ('sensor') STORED) STRICT",
+        result.to_string()
+    );
+
+    let table = CreateTable::new(
+        "settings",
+        [
+            Column::new("key", Types::String).primary_key(),
+            Column::new("value", Types::Binary),
+        ],
+    )
+    .strict()
+    .without_rowid();
+    let result = formatter.format(&table).unwrap();
+    assert_eq!(
+        "CREATE TABLE settings (key TEXT PRIMARY KEY,value BLOB) STRICT, WITHOUT ROWID",
+        result.to_string()
+    );
+
+    let table = CreateTable::new("broken", [Column::new("key", Types::String)]).without_rowid();
+    let result = formatter.format(&table);
+    assert_eq!(
+        Err(FormatError::Unsupported {
+            feature: "WITHOUT ROWID on a Table without a PRIMARY KEY".to_string()
+        }),
+        result.map(String::from)
+    );
+
+    let table = CreateTable::new(
+        "broken",
+        [Column::new("id", Types::Integer).autoincrement()],
+    )
+    .without_rowid();
+    let result = formatter.format(&table);
+    assert_eq!(
+        Err(FormatError::Unsupported {
+            feature: "AUTOINCREMENT on a WITHOUT ROWID Table".to_string()
+        }),
+        result.map(String::from)
+    );
+
+    let table = CreateTable::new("broken", [Column::new("id", Types::String).autoincrement()]);
+    let result = formatter.format(&table);
+    assert_eq!(
+        Err(FormatError::Unsupported {
+            feature: "AUTOINCREMENT on a Column that is not an INTEGER PRIMARY KEY".to_string()
+        }),
+        result.map(String::from)
+    );
+
+    // Generated Columns are not copied over when the Table is rebuilt
+    let alter = AlterTable::new("readings")
+        .alter_column_type("celsius", Types::Integer)
+        .definition(CreateTable::new(
+            "readings",
+            [
+                Column::new("celsius", Types::Float),
+                Column::new("doubled", Types::Float).generated(
+                    Expression::column("celsius").multiply(Expression::value(2)),
+                    Generated::Stored,
+                ),
+            ],
+        ));
+    let result = formatter.format(&alter).unwrap();
+    assert_eq!(
+        "SAVEPOINT alter_readings; CREATE TABLE new_readings (celsius INTEGER,doubled REAL GENERATED ALWAYS AS (celsius*2) STORED); INSERT INTO new_readings (celsius) SELECT celsius FROM readings; DROP TABLE readings; ALTER TABLE new_readings RENAME TO readings; PRAGMA foreign_key_check; RELEASE SAVEPOINT alter_readings",
+        result.to_string()
+    );
+}
+
+#[test]
+fn full_text_search() {
+    let mut formatter = sqlf::fmt::sqlite::SqliteFormatter::new();
+
+    let table = CreateFtsTable::new("docs", ["title", "body"])
+        .unindexed("created")
+        .tokenize("porter unicode61");
+    let result = formatter.format(&table).unwrap();
+    assert_eq!(
+        "CREATE VIRTUAL TABLE docs USING fts5(title,body,created UNINDEXED,tokenize='porter unicode61')",
+        result.to_string()
+    );
+
+    let select = Select::new(
+        "docs",
+        FtsMatch::new("docs", Expression::parameter()),
+        (
+            "title",
+            FtsCall::highlight("docs", 0, "<b>", "</b>").alias("marked"),
+            FtsCall::snippet("docs", None, "[", "]", "...", 16),
+            FtsCall::bm25_weighted("docs", [10.0, 1.5]).alias("score"),
+        ),
+    )
+    .order_by(OrderBy::asc("rank"));
+    let result = formatter.format(&select).unwrap();
+    assert_eq!(
+        "SELECT title,highlight(docs, 0, '<b>', '</b>') AS marked,snippet(docs, -1, '[', ']', '...', 16),bm25(docs, 10, 1.5) AS score FROM docs WHERE docs MATCH ? ORDER BY rank ASC",
+        result.to_string()
+    );
+}
+
+#[test]
+fn json() {
+    let mut formatter = sqlf::fmt::sqlite::SqliteFormatter::new();
+
+    let select = Select::new(
+        "products",
+        Expression::column("attributes")
+            .json_text("color")
+            .equals(Expression::text("red")),
+        (
+            "name",
+            Expression::column("attributes")
+                .json(JsonPath::root().key("sizes").index(0))
+                .alias("smallest"),
+            Expression::json_object([
+                ("id", Expression::column("id")),
+                (
+                    "tags",
+                    Expression::json_array([Expression::text("new"), Expression::value(1)]),
+                ),
+            ])
+            .alias("summary"),
+        ),
+    );
+    let result = formatter.format(&select).unwrap();
+    assert_eq!(
+        "SELECT name,attributes->'$.sizes[0]' AS smallest,json_object('id', id, 'tags', json_array('new', 1)) AS summary FROM products WHERE (attributes->>'$.color')='red'",
+        result.to_string()
+    );
+
+    let select = Select::new(
+        "products".cross_join(
+            JsonEach::new("products.attributes")
+                .path("tags")
+                .alias("tag"),
+        ),
+        (),
+        &["products.name", "tag.value"],
+    );
+    let result = formatter.format(&select).unwrap();
+    assert_eq!(
+        "SELECT products.name,tag.value FROM products CROSS JOIN json_each(products.attributes, '$.tags') AS tag",
+        result.to_string()
+    );
+}
+
+#[test]
+fn date_time() {
+    let mut formatter = sqlf::fmt::sqlite::SqliteFormatter::new();
+
+    let select = Select::new(
+        "orders",
+        Expression::column("created").compare(
+            Comparison::GreaterEqual,
+            Expression::now().date_sub(Interval::days(7)),
+        ),
+        (
+            Expression::column("created")
+                .date_trunc(DateTimeUnit::Month)
+                .alias("month"),
+            Expression::column("created")
+                .date_trunc(DateTimeUnit::Hour)
+                .alias("hour"),
+            Expression::column("created")
+                .date_part(DatePart::DayOfWeek)
+                .alias("weekday"),
+            Expression::column("created")
+                .date_add(Interval::months(1))
+                .date_format("%Y-%m-%d")
+                .alias("due"),
+        ),
+    );
+    let result = formatter.format(&select).unwrap();
+    assert_eq!(
+        "SELECT datetime(created, 'start of month') AS month,strftime('%Y-%m-%d %H:00:00', created) AS hour,CAST(strftime('%w', created) AS INTEGER) AS weekday,strftime('%Y-%m-%d', datetime(created, '+1 months')) AS due FROM orders WHERE created>=datetime(datetime('now'), '-7 days')",
+        result.to_string()
+    );
+
+    let select = Select::new(
+        "orders",
+        (),
+        Expression::now()
+            .date_sub(Interval::seconds(i64::MIN))
+            .alias("start"),
+    );
+    assert_eq!(
+        Err(FormatError::Invalid {
+            reason: "The Interval -9223372036854775808 can't be subtracted".to_string()
+        }),
+        formatter.format(&select).map(String::from)
+    );
+}
+
+#[test]
+fn collation() {
+    let mut formatter = sqlf::fmt::sqlite::SqliteFormatter::new();
+
+    let table = CreateTable::new(
+        "users",
+        [
+            Column::new("email", Types::String)
+                .primary_key()
+                .collate(Collation::NoCase),
+            Column::new("name", Types::String).collate(Collation::named("unicode")),
+        ],
+    );
+    let result = formatter.format(&table).unwrap();
+    assert_eq!(
+        "CREATE TABLE users (email TEXT PRIMARY KEY COLLATE NOCASE,name TEXT COLLATE unicode)",
+        result.to_string()
+    );
+
+    let select = Select::new(
+        "users",
+        Expression::column("name")
+            .collate(Collation::NoCase)
+            .equals(Expression::parameter()),
+        &["email"],
+    )
+    .order_by(OrderBy::asc("name").collate(Collation::RTrim))
+    .order_by(
+        OrderBy::desc(Expression::column("first").concat(Expression::column("last")))
+            .collate(Collation::Binary),
+    );
+    let result = formatter.format(&select).unwrap();
+    assert_eq!(
+        "SELECT email FROM users WHERE name COLLATE NOCASE=? ORDER BY name COLLATE RTRIM ASC,(first||last) COLLATE BINARY DESC",
+        result.to_string()
+    );
+}
@@ -1,4 +1,9 @@
-use sqlf::verify::VerifyError;
+use sqlf::{
+    verify::{IndexDefinition, VerifyError, VerifyReturning, VerifyTable},
+    Aggregate, AllFields, Comparison, CreateFtsTable, CreateIndex, CreateView, Cte, Delete, Exists,
+    Expression, FtsCall, FtsMatch, InSelect, Insert, InsertSelect, JsonEach, OrderBy, Over, Table,
+    Update, Window, With,
+};
 
 #[test]
 fn valid_select() {
@@ -71,3 +76,927 @@ fn missing_table_select() {
         verify_result.unwrap_err()
     );
 }
+
+#[test]
+fn joined_select() {
+    let table_def = {
+        let mut tmp = sqlf::verify::RootTableDefinitions::new();
+
+        tmp.add_table(
+            "users",
+            ["id", "name"].iter().map(|s| s.to_string()).collect(),
+        );
+        tmp.add_table(
+            "orders",
+            ["id", "user_id"].iter().map(|s| s.to_string()).collect(),
+        );
+
+        tmp
+    };
+
+    let query = sqlf::Select::new(
+        "users".inner_join("orders".alias("o"), ("users.id", "o.user_id")),
+        (),
+        &["users.name", "o.id"],
+    );
+    assert!(sqlf::verify::verify_select(query, &table_def).is_ok());
+
+    let query = sqlf::Select::new(
+        "users".inner_join("orders".alias("o"), ("users.id", "o.user_id")),
+        (),
+        &["orders.id"],
+    );
+    assert_eq!(
+        Err(VerifyError::MissingField {
+            field: "orders.id".to_string()
+        }),
+        sqlf::verify::verify_select(query, &table_def)
+    );
+
+    // Fields of Expressions are kept, even if their Name contains a Dot
+    let aliased = sqlf::Select::new("users", (), Expression::value(1.5)).alias("s");
+    assert_eq!(
+        Ok(["1.5".to_string(), "s.1.5".to_string()]
+            .into_iter()
+            .collect()),
+        aliased.get_fields(&table_def)
+    );
+}
+
+#[test]
+fn ordered_select() {
+    let table_def = {
+        let mut tmp = sqlf::verify::RootTableDefinitions::new();
+
+        tmp.add_table(
+            "test",
+            ["first", "second"].iter().map(|s| s.to_string()).collect(),
+        );
+
+        tmp
+    };
+
+    let query = sqlf::Select::new("test", (), &["first"]).order_by(OrderBy::asc("second"));
+    assert!(sqlf::verify::verify_select(query, &table_def).is_ok());
+
+    let query = sqlf::Select::new("test", (), &["first"]).order_by(OrderBy::asc("other"));
+    assert_eq!(
+        Err(VerifyError::MissingField {
+            field: "other".to_string()
+        }),
+        sqlf::verify::verify_select(query, &table_def)
+    );
+}
+
+#[test]
+fn grouped_select() {
+    let table_def = {
+        let mut tmp = sqlf::verify::RootTableDefinitions::new();
+
+        tmp.add_table(
+            "test",
+            ["first", "second"].iter().map(|s| s.to_string()).collect(),
+        );
+
+        tmp
+    };
+
+    let query = sqlf::Select::new("test", (), (&["first"], Aggregate::sum("second")))
+        .group_by("first")
+        .order_by(OrderBy::desc("sum(second)"));
+    assert!(sqlf::verify::verify_select(query, &table_def).is_ok());
+
+    let query = sqlf::Select::new(
+        "test",
+        (),
+        (&["first", "second"], Aggregate::count_all().alias("n")),
+    )
+    .group_by("first");
+    assert_eq!(
+        Err(VerifyError::UngroupedField {
+            field: "second".to_string()
+        }),
+        sqlf::verify::verify_select(query, &table_def)
+    );
+
+    let query = sqlf::Select::new("test", (), (&["first"], Aggregate::count_all()));
+    assert_eq!(
+        Err(VerifyError::UngroupedField {
+            field: "first".to_string()
+        }),
+        sqlf::verify::verify_select(query, &table_def)
+    );
+
+    let query = sqlf::Select::new(
+        "test",
+        (),
+        (
+            &["first"],
+            Aggregate::sum(Expression::column("second").multiply("second")),
+        ),
+    )
+    .group_by("first")
+    .having(Aggregate::avg("second").compare(Comparison::Greater, 5));
+    assert!(sqlf::verify::verify_select(query, &table_def).is_ok());
+
+    let query = sqlf::Select::new("test", (), (&["first"], Aggregate::count_all()))
+        .group_by("first")
+        .having(Aggregate::sum("third").compare(Comparison::Greater, 5));
+    assert_eq!(
+        Err(VerifyError::MissingField {
+            field: "third".to_string()
+        }),
+        sqlf::verify::verify_select(query, &table_def)
+    );
+
+    let query =
+        sqlf::Select::new("test", (), (&["test.first"], Aggregate::count_all())).group_by("first");
+    assert!(sqlf::verify::verify_select(query, &table_def).is_ok());
+
+    // Columns with the same Name in different Tables are not interchangeable
+    let query = sqlf::Select::new(
+        "test"
+            .alias("a")
+            .inner_join("test".alias("b"), ("a.first", "b.second")),
+        (),
+        (&["b.first"], Aggregate::count_all()),
+    )
+    .group_by("a.first");
+    assert_eq!(
+        Err(VerifyError::UngroupedField {
+            field: "b.first".to_string()
+        }),
+        sqlf::verify::verify_select(query, &table_def)
+    );
+}
+
+#[test]
+fn distinct_on_select() {
+    let table_def = {
+        let mut tmp = sqlf::verify::RootTableDefinitions::new();
+
+        tmp.add_table(
+            "test",
+            ["first", "second"].iter().map(|s| s.to_string()).collect(),
+        );
+
+        tmp
+    };
+
+    let query = sqlf::Select::new("test", (), &["first"]).distinct_on(["other"]);
+    assert_eq!(
+        Err(VerifyError::MissingField {
+            field: "other".to_string()
+        }),
+        sqlf::verify::verify_select(query, &table_def)
+    );
+}
+
+#[test]
+fn compound_select() {
+    let table_def = {
+        let mut tmp = sqlf::verify::RootTableDefinitions::new();
+
+        tmp.add_table(
+            "test",
+            ["first", "second"].iter().map(|s| s.to_string()).collect(),
+        );
+        tmp.add_table(
+            "other",
+            ["third", "fourth"].iter().map(|s| s.to_string()).collect(),
+        );
+
+        tmp
+    };
+
+    let query = sqlf::Select::new(
+        sqlf::Select::new("test", (), &["first", "second"]).union(sqlf::Select::new(
+            "other",
+            (),
+            AllFields {},
+        )),
+        (),
+        &["first"],
+    );
+    assert!(sqlf::verify::verify_select(query, &table_def).is_ok());
+
+    let query = sqlf::Select::new(
+        sqlf::Select::new("test", (), &["first", "second"]).intersect(sqlf::Select::new(
+            "other",
+            (),
+            &["third"],
+        )),
+        (),
+        &["first"],
+    );
+    assert_eq!(
+        Err(VerifyError::ColumnCountMismatch {
+            expected: 2,
+            found: 1
+        }),
+        sqlf::verify::verify_select(query, &table_def)
+    );
+
+    // Columns with the same Name are still counted separately
+    let query = sqlf::Select::new(
+        sqlf::Select::new(
+            "test"
+                .alias("a")
+                .inner_join("test".alias("b"), ("a.first", "b.second")),
+            (),
+            &["a.first", "b.first"],
+        )
+        .union(sqlf::Select::new("other", (), &["third"])),
+        (),
+        &["first"],
+    );
+    assert_eq!(
+        Err(VerifyError::ColumnCountMismatch {
+            expected: 2,
+            found: 1
+        }),
+        sqlf::verify::verify_select(query, &table_def)
+    );
+}
+
+#[test]
+fn with_select() {
+    let table_def = {
+        let mut tmp = sqlf::verify::RootTableDefinitions::new();
+
+        tmp.add_table(
+            "categories",
+            ["id", "parent", "name"]
+                .iter()
+                .map(|s| s.to_string())
+                .collect(),
+        );
+
+        tmp
+    };
+
+    let tree = sqlf::Select::new("categories", ("id", "1"), &["id", "parent"]).union_all(
+        sqlf::Select::new(
+            "categories"
+                .alias("c")
+                .inner_join("tree", ("c.parent", "tree.id")),
+            (),
+            &["c.id", "c.parent"],
+        ),
+    );
+    let query = With::recursive(
+        Cte::new("tree", tree),
+        sqlf::Select::new("tree", (), &["parent"]),
+    );
+    assert_eq!(
+        Ok(["parent".to_string()].into_iter().collect()),
+        query.get_fields(&table_def)
+    );
+
+    // The Expression is not visible outside of the With
+    assert!(table_def.get_table("tree").is_none());
+
+    let query = With::new(
+        Cte::new(
+            "named",
+            sqlf::Select::new("categories", (), &["id", "name"]),
+        )
+        .columns(["key", "value"]),
+        sqlf::Select::new("named", (), &["name"]),
+    );
+    assert_eq!(
+        Err(VerifyError::MissingField {
+            field: "name".to_string()
+        }),
+        query.get_fields(&table_def)
+    );
+
+    // Both Columns are named id, but they still have to be renamed separately
+    let query = With::new(
+        Cte::new(
+            "pairs",
+            sqlf::Select::new(
+                "categories"
+                    .alias("child")
+                    .inner_join("categories".alias("parent"), ("child.parent", "parent.id")),
+                (),
+                &["child.id", "parent.id"],
+            ),
+        )
+        .columns(["child"]),
+        sqlf::Select::new("pairs", (), &["child"]),
+    );
+    assert_eq!(
+        Err(VerifyError::ColumnCountMismatch {
+            expected: 1,
+            found: 2
+        }),
+        query.get_fields(&table_def)
+    );
+
+    let query = With::new(
+        Cte::new(
+            "named",
+            sqlf::Select::new("categories", (), &["id", "name"]),
+        )
+        .columns(["key", "key"]),
+        sqlf::Select::new("named", (), &["key"]),
+    );
+    assert_eq!(
+        Err(VerifyError::DuplicateColumn {
+            column: "key".to_string()
+        }),
+        query.get_fields(&table_def)
+    );
+}
+
+#[test]
+fn subquery_select() {
+    let table_def = {
+        let mut tmp = sqlf::verify::RootTableDefinitions::new();
+
+        tmp.add_table(
+            "users",
+            ["id", "name"].iter().map(|s| s.to_string()).collect(),
+        );
+        tmp.add_table(
+            "orders",
+            ["id", "user_id", "total"]
+                .iter()
+                .map(|s| s.to_string())
+                .collect(),
+        );
+
+        tmp
+    };
+
+    let query = sqlf::Select::new(
+        "users",
+        InSelect::new("id", sqlf::Select::new("orders", (), &["user_id"])),
+        &["name"],
+    );
+    assert!(sqlf::verify::verify_select(query, &table_def).is_ok());
+
+    let query = sqlf::Select::new(
+        "users",
+        InSelect::new("id", sqlf::Select::new("orders", (), &["user_id", "total"])),
+        &["name"],
+    );
+    assert_eq!(
+        Err(VerifyError::ColumnCountMismatch {
+            expected: 1,
+            found: 2
+        }),
+        sqlf::verify::verify_select(query, &table_def)
+    );
+
+    let query = sqlf::Select::new(
+        "users",
+        InSelect::new(
+            "id",
+            sqlf::Select::new(
+                "users"
+                    .alias("u")
+                    .inner_join("orders".alias("o"), ("u.id", "o.user_id")),
+                (),
+                &["u.id", "o.id"],
+            ),
+        ),
+        &["name"],
+    );
+    assert_eq!(
+        Err(VerifyError::ColumnCountMismatch {
+            expected: 1,
+            found: 2
+        }),
+        sqlf::verify::verify_select(query, &table_def)
+    );
+
+    let query = sqlf::Select::new(
+        "users",
+        Exists::new(sqlf::Select::new("orders", ("amount", "10"), AllFields {})),
+        &["name"],
+    );
+    assert_eq!(
+        Err(VerifyError::MissingField {
+            field: "amount".to_string()
+        }),
+        sqlf::verify::verify_select(query, &table_def)
+    );
+
+    let query = sqlf::Select::new("users", ("other", "'value'"), &["name"]);
+    assert_eq!(
+        Err(VerifyError::MissingField {
+            field: "other".to_string()
+        }),
+        sqlf::verify::verify_select(query, &table_def)
+    );
+}
+
+#[test]
+fn correlated_subquery_select() {
+    let table_def = {
+        let mut tmp = sqlf::verify::RootTableDefinitions::new();
+
+        tmp.add_table(
+            "users",
+            ["id", "name"].iter().map(|s| s.to_string()).collect(),
+        );
+        tmp.add_table(
+            "orders",
+            ["id", "user_id", "total"]
+                .iter()
+                .map(|s| s.to_string())
+                .collect(),
+        );
+
+        tmp
+    };
+
+    let query = sqlf::Select::new(
+        "users".alias("u"),
+        Exists::new(sqlf::Select::new(
+            "orders".alias("o"),
+            ("o.user_id", "u.id"),
+            AllFields {},
+        )),
+        &["u.name"],
+    );
+    assert!(sqlf::verify::verify_select(query, &table_def).is_ok());
+
+    let query = sqlf::Select::new(
+        "users".alias("u"),
+        Exists::new(sqlf::Select::new(
+            "orders".alias("o"),
+            ("o.user_id", "u.user_id"),
+            AllFields {},
+        )),
+        &["u.name"],
+    );
+    assert_eq!(
+        Err(VerifyError::MissingField {
+            field: "u.user_id".to_string()
+        }),
+        sqlf::verify::verify_select(query, &table_def)
+    );
+
+    // Like in SQL, the innermost Query that provides the Column is used
+    let query = sqlf::Select::new(
+        "users",
+        Exists::new(sqlf::Select::new("orders", ("user_id", "id"), AllFields {})),
+        &["name"],
+    );
+    assert!(sqlf::verify::verify_select(query, &table_def).is_ok());
+
+    let query = sqlf::Select::new(
+        "users",
+        InSelect::new("id", sqlf::Select::new("orders", (), &["id"])),
+        &["name"],
+    );
+    assert!(sqlf::verify::verify_select(query, &table_def).is_ok());
+
+    let query = sqlf::Select::new(
+        "users",
+        Exists::new(sqlf::Select::new(
+            "orders",
+            ("user_id", "name"),
+            AllFields {},
+        )),
+        &["name"],
+    );
+    assert_eq!(
+        Err(VerifyError::AmbiguousField {
+            field: "name".to_string()
+        }),
+        sqlf::verify::verify_select(query, &table_def)
+    );
+
+    let query = sqlf::Select::new(
+        "users"
+            .alias("u")
+            .inner_join("orders".alias("o"), ("u.id", "o.user_id")),
+        ("id", "1"),
+        &["u.name"],
+    );
+    assert_eq!(
+        Err(VerifyError::AmbiguousField {
+            field: "id".to_string()
+        }),
+        sqlf::verify::verify_select(query, &table_def)
+    );
+}
+
+#[test]
+fn window_functions() {
+    let table_def = {
+        let mut tmp = sqlf::verify::RootTableDefinitions::new();
+
+        tmp.add_table(
+            "employees",
+            ["name", "department", "salary"]
+                .iter()
+                .map(|s| s.to_string())
+                .collect(),
+        );
+
+        tmp
+    };
+
+    let query = sqlf::Select::new(
+        "employees",
+        (),
+        (
+            "name",
+            Aggregate::sum("salary")
+                .over(Window::new().partition_by("department"))
+                .alias("total"),
+        ),
+    );
+    assert!(sqlf::verify::verify_select(query, &table_def).is_ok());
+
+    let query = sqlf::Select::new(
+        "employees",
+        (),
+        Over::rank(
+            Window::new()
+                .partition_by("team")
+                .order_by(OrderBy::desc("salary")),
+        ),
+    );
+    assert_eq!(
+        Err(VerifyError::MissingField {
+            field: "team".to_string()
+        }),
+        sqlf::verify::verify_select(query, &table_def)
+    );
+}
+
+#[test]
+fn expressions() {
+    let table_def = {
+        let mut tmp = sqlf::verify::RootTableDefinitions::new();
+
+        tmp.add_table(
+            "orders",
+            ["price", "quantity", "discount"]
+                .iter()
+                .map(|s| s.to_string())
+                .collect(),
+        );
+
+        tmp
+    };
+
+    let query = sqlf::Select::new(
+        "orders",
+        Expression::column("quantity").compare(sqlf::Comparison::Greater, 1),
+        Expression::column("price")
+            .multiply("quantity")
+            .minus(Expression::coalesce(["discount", "0"]))
+            .alias("total"),
+    )
+    .order_by(OrderBy::desc("total"));
+    assert_eq!(
+        Err(VerifyError::MissingField {
+            field: "0".to_string()
+        }),
+        sqlf::verify::verify_select(query, &table_def)
+    );
+
+    let query = sqlf::Select::new(
+        "orders",
+        Expression::column("quantity").compare(sqlf::Comparison::Greater, 1),
+        Expression::column("price")
+            .multiply("quantity")
+            .minus(Expression::coalesce([
+                Expression::column("discount"),
+                Expression::value(0),
+            ]))
+            .alias("total"),
+    )
+    .order_by(OrderBy::desc("total"));
+    assert!(sqlf::verify::verify_select(query, &table_def).is_ok());
+
+    let query = sqlf::Select::new("orders", Expression::column("amount").is_null(), &["price"]);
+    assert_eq!(
+        Err(VerifyError::MissingField {
+            field: "amount".to_string()
+        }),
+        sqlf::verify::verify_select(query, &table_def)
+    );
+}
+
+#[test]
+fn returning() {
+    let table_def = {
+        let mut tmp = sqlf::verify::RootTableDefinitions::new();
+
+        tmp.add_table(
+            "users",
+            ["id", "name", "visits"]
+                .iter()
+                .map(|s| s.to_string())
+                .collect(),
+        );
+
+        tmp
+    };
+
+    let insert = Insert::new("users")
+        .value("name", Expression::text("test"))
+        .returning(&["users.id", "name"]);
+    assert_eq!(
+        Ok(["id", "name"].iter().map(|s| s.to_string()).collect()),
+        insert.get_returned_fields(&table_def)
+    );
+
+    let insert = Insert::new("users").value("email", Expression::text("test"));
+    assert_eq!(
+        Err(VerifyError::MissingField {
+            field: "email".to_string()
+        }),
+        insert.get_returned_fields(&table_def)
+    );
+
+    let update = Update::new("users", ("id", "1"))
+        .set("visits", Expression::column("visit").plus(1))
+        .returning(&["visits"]);
+    assert_eq!(
+        Err(VerifyError::MissingField {
+            field: "visit".to_string()
+        }),
+        update.get_returned_fields(&table_def)
+    );
+
+    let delete = Delete::new("users", ("id", "1")).returning(AllFields {});
+    assert_eq!(
+        Ok(["id", "name", "visits"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect()),
+        delete.get_returned_fields(&table_def)
+    );
+
+    let delete = Delete::new("users", ("id", "1"));
+    assert_eq!(
+        Ok(Default::default()),
+        delete.get_returned_fields(&table_def)
+    );
+}
+
+#[test]
+fn insert_select() {
+    let table_def = {
+        let mut tmp = sqlf::verify::RootTableDefinitions::new();
+
+        tmp.add_table(
+            "users",
+            ["id", "name", "active"]
+                .iter()
+                .map(|s| s.to_string())
+                .collect(),
+        );
+        tmp.add_table(
+            "archive",
+            ["id", "name"].iter().map(|s| s.to_string()).collect(),
+        );
+
+        tmp
+    };
+
+    let insert = InsertSelect::new(
+        "archive",
+        ["id", "name"],
+        sqlf::Select::new("users", ("active", "0"), &["users.id", "name"]),
+    );
+    assert!(insert.get_returned_fields(&table_def).is_ok());
+
+    let insert = InsertSelect::new(
+        "archive",
+        ["id", "name"],
+        sqlf::Select::new("users", (), &["id"]),
+    );
+    assert_eq!(
+        Err(VerifyError::ColumnCountMismatch {
+            expected: 2,
+            found: 1
+        }),
+        insert.get_returned_fields(&table_def)
+    );
+
+    let insert = InsertSelect::new(
+        "archive",
+        ["id", "name"],
+        sqlf::Select::new(
+            "users"
+                .alias("a")
+                .inner_join("users".alias("b"), ("a.id", "b.id")),
+            (),
+            &["a.id", "b.id", "a.name"],
+        ),
+    );
+    assert_eq!(
+        Err(VerifyError::ColumnCountMismatch {
+            expected: 2,
+            found: 3
+        }),
+        insert.get_returned_fields(&table_def)
+    );
+
+    let insert = InsertSelect::new("archive", Vec::<String>::new(), "users");
+    assert_eq!(
+        Err(VerifyError::ColumnCountMismatch {
+            expected: 2,
+            found: 3
+        }),
+        insert.get_returned_fields(&table_def)
+    );
+
+    let insert = InsertSelect::new(
+        "archive",
+        ["id", "title"],
+        sqlf::Select::new("users", (), &["id", "name"]),
+    );
+    assert_eq!(
+        Err(VerifyError::MissingField {
+            field: "title".to_string()
+        }),
+        insert.get_returned_fields(&table_def)
+    );
+}
+
+#[test]
+fn create_index() {
+    let mut table_def = sqlf::verify::RootTableDefinitions::new();
+    table_def.add_table(
+        "users",
+        ["id", "name", "deleted"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect(),
+    );
+
+    let index = CreateIndex::new("users_name", "users", [OrderBy::asc("name")])
+        .unique()
+        .condition(Expression::column("deleted").is_null());
+    assert!(index.register(&mut table_def).is_ok());
+    assert_eq!(
+        Some(&IndexDefinition {
+            table: "users".to_string(),
+            columns: vec!["name".to_string()],
+            unique: true,
+            partial: true,
+        }),
+        table_def.get_index("users_name")
+    );
+    assert_eq!(1, table_def.indexes_on("users").count());
+
+    let index = CreateIndex::new("users_email", "users", [OrderBy::asc("email")]);
+    assert_eq!(
+        Err(VerifyError::MissingField {
+            field: "email".to_string()
+        }),
+        index.register(&mut table_def)
+    );
+
+    let index = CreateIndex::new("groups_name", "groups", [OrderBy::asc("name")]);
+    assert_eq!(
+        Err(VerifyError::UnknownTable {
+            table: "groups".to_string()
+        }),
+        index.register(&mut table_def)
+    );
+}
+
+#[test]
+fn create_view() {
+    let mut table_def = sqlf::verify::RootTableDefinitions::new();
+    table_def.add_table(
+        "users",
+        ["id", "name", "active"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect(),
+    );
+
+    let view = CreateView::new(
+        "active_users",
+        sqlf::Select::new("users", ("active", "1"), &["id", "name"]),
+    );
+    assert!(view.register(&mut table_def).is_ok());
+
+    let query = sqlf::Select::new("active_users", (), &["name"]);
+    assert!(sqlf::verify::verify_select(query, &table_def).is_ok());
+
+    let query = sqlf::Select::new("active_users", (), &["active"]);
+    assert_eq!(
+        Err(VerifyError::MissingField {
+            field: "active".to_string()
+        }),
+        sqlf::verify::verify_select(query, &table_def)
+    );
+
+    let view = CreateView::new(
+        "user_names",
+        sqlf::Select::new("users", (), &["id", "name"]),
+    )
+    .columns(["user_id", "user_name"]);
+    assert!(view.register(&mut table_def).is_ok());
+    let query = sqlf::Select::new("user_names", (), &["user_name"]);
+    assert!(sqlf::verify::verify_select(query, &table_def).is_ok());
+
+    let view = CreateView::new("broken", sqlf::Select::new("users", (), &["id"]))
+        .columns(["user_id", "user_name"]);
+    assert_eq!(
+        Err(VerifyError::ColumnCountMismatch {
+            expected: 2,
+            found: 1
+        }),
+        view.register(&mut table_def)
+    );
+
+    let pairs = || {
+        sqlf::Select::new(
+            "users"
+                .alias("a")
+                .inner_join("users".alias("b"), ("a.id", "b.id")),
+            (),
+            &["a.id", "b.id"],
+        )
+    };
+    let view = CreateView::new("pairs", pairs()).columns(["first"]);
+    assert_eq!(
+        Err(VerifyError::ColumnCountMismatch {
+            expected: 1,
+            found: 2
+        }),
+        view.register(&mut table_def)
+    );
+
+    let view = CreateView::new("pairs", pairs());
+    assert_eq!(
+        Err(VerifyError::DuplicateColumn {
+            column: "id".to_string()
+        }),
+        view.register(&mut table_def)
+    );
+
+    let view = CreateView::new("pairs", pairs()).columns(["first", "second"]);
+    assert!(view.register(&mut table_def).is_ok());
+}
+
+#[test]
+fn full_text_search() {
+    let mut table_def = sqlf::verify::RootTableDefinitions::new();
+    CreateFtsTable::new("docs", ["title", "body"]).register(&mut table_def);
+
+    let query = sqlf::Select::new(
+        "docs",
+        FtsMatch::new("docs", Expression::text("sqlite")),
+        ("title", FtsCall::bm25("docs"), "rank"),
+    );
+    assert!(sqlf::verify::verify_select(query, &table_def).is_ok());
+
+    let query = sqlf::Select::new(
+        "docs",
+        FtsMatch::new("summary", Expression::text("sqlite")),
+        "title",
+    );
+    assert_eq!(
+        Err(VerifyError::MissingField {
+            field: "summary".to_string()
+        }),
+        sqlf::verify::verify_select(query, &table_def)
+    );
+}
+
+#[test]
+fn json_each() {
+    let mut table_def = sqlf::verify::RootTableDefinitions::new();
+    table_def.add_table(
+        "products",
+        ["name", "attributes"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect(),
+    );
+
+    let query = sqlf::Select::new(
+        "products".cross_join(JsonEach::new("products.attributes").alias("tag")),
+        (),
+        &["name", "tag.value"],
+    );
+    assert!(sqlf::verify::verify_select(query, &table_def).is_ok());
+
+    let query = sqlf::Select::new(
+        "products".cross_join(JsonEach::new("products.attributes").alias("tag")),
+        (),
+        &["tag.element"],
+    );
+    assert_eq!(
+        Err(VerifyError::MissingField {
+            field: "tag.element".to_string()
+        }),
+        sqlf::verify::verify_select(query, &table_def)
+    );
+}